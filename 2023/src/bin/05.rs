@@ -1,13 +1,13 @@
 use std::{collections::HashMap, ops::RangeInclusive, str::FromStr};
 
-advent_of_code::solution!(5);
+advent_of_code::solution!(5, parse = parse);
 
 #[derive(Debug)]
 struct ParseAlmanacErr;
 
 #[derive(Debug)]
 struct Almanac {
-    seeds: Vec<RangeInclusive<u64>>,
+    seeds: Vec<u64>,
     maps: HashMap<String, Map>,
 }
 
@@ -31,36 +31,19 @@ impl Almanac {
         values.iter().map(|id| id.start()).min().unwrap().to_owned()
     }
 
-    fn with_ranges(s: &str) -> Result<Self, ParseAlmanacErr> {
-        let parts = s.split_once("\n").unwrap();
+    fn seed_ids(&self) -> Vec<RangeInclusive<u64>> {
+        self.seeds.iter().map(|&id| id..=id).collect()
+    }
 
-        let seeds = parts
-            .0
-            .strip_prefix("seeds: ")
-            .unwrap()
-            .split_whitespace()
-            .collect::<Vec<&str>>()
+    fn seed_ranges(&self) -> Vec<RangeInclusive<u64>> {
+        self.seeds
             .chunks(2)
             .map(|chunk| {
-                let (start, len) = (
-                    chunk[0].parse::<u64>().unwrap(),
-                    chunk[1].parse::<u64>().unwrap(),
-                );
+                let (start, len) = (chunk[0], chunk[1]);
                 let end = start + len;
                 start..=end - 1
             })
-            .collect::<Vec<RangeInclusive<u64>>>();
-
-        let maps = parts
-            .1
-            .split("\n\n")
-            .map(|s| {
-                let map: Map = s.parse().unwrap();
-                Ok((map.src_category.to_owned(), map))
-            })
-            .collect::<Result<HashMap<String, Map>, ParseAlmanacErr>>()?;
-
-        Ok(Almanac { seeds, maps })
+            .collect()
     }
 }
 
@@ -75,11 +58,8 @@ impl FromStr for Almanac {
             .strip_prefix("seeds: ")
             .unwrap()
             .split_whitespace()
-            .map(|s| {
-                let id = s.parse::<u64>().unwrap();
-                id..=id
-            })
-            .collect::<Vec<RangeInclusive<u64>>>();
+            .map(|s| s.parse::<u64>().unwrap())
+            .collect::<Vec<u64>>();
 
         let maps = parts
             .1
@@ -220,18 +200,16 @@ impl FromStr for ConversionRange {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let almanac = Almanac::from_str(input).unwrap();
-
-    Some(almanac.min_soil_number(almanac.seeds.to_owned()))
+fn parse(input: &str) -> Almanac {
+    Almanac::from_str(input).unwrap()
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let almanac = Almanac::with_ranges(input).unwrap();
-
-    dbg!(&almanac);
+fn part_one(almanac: &Almanac) -> Option<u64> {
+    Some(almanac.min_soil_number(almanac.seed_ids()))
+}
 
-    Some(almanac.min_soil_number(almanac.seeds.to_owned()))
+fn part_two(almanac: &Almanac) -> Option<u64> {
+    Some(almanac.min_soil_number(almanac.seed_ranges()))
 }
 
 #[cfg(test)]
@@ -240,13 +218,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse(&advent_of_code::template::read_file("examples", DAY)));
         assert_eq!(result, Some(35));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse(&advent_of_code::template::read_file("examples", DAY)));
         assert_eq!(result, Some(46));
     }
 }
@@ -1,6 +0,0 @@
-pub mod all;
-pub mod download;
-pub mod read;
-pub mod scaffold;
-pub mod solve;
-pub mod time;
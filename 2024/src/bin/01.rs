@@ -1,4 +1,4 @@
-use std::{collections::HashMap, str::FromStr};
+use std::{collections::HashMap, fmt::Display, str::FromStr};
 
 advent_of_code::solution!(1);
 
@@ -30,7 +30,13 @@ impl ListPair {
 }
 
 #[derive(Debug)]
-struct ParseListPairErr;
+struct ParseListPairErr(String);
+
+impl Display for ParseListPairErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid line `{}`", self.0)
+    }
+}
 
 impl FromStr for ListPair {
     type Err = ParseListPairErr;
@@ -39,17 +45,16 @@ impl FromStr for ListPair {
         let (left, right): (Vec<_>, Vec<_>) = s
             .lines()
             .map(|l| {
-                let mut parts = l.split_whitespace();
-                let (l, r) = (
-                    parts.next().expect("input lines composed of 2 parts"),
-                    parts.next().expect("input lines composed of 2 parts"),
-                );
-
-                (
-                    l.parse::<LocationID>().expect("IDs are LocationIDs"),
-                    r.parse::<LocationID>().expect("IDs are LocationIDs"),
-                )
+                let err = || ParseListPairErr(l.to_string());
+                let (left, right) = l.split_once(char::is_whitespace).ok_or_else(err)?;
+
+                Ok((
+                    left.trim().parse::<LocationID>().map_err(|_| err())?,
+                    right.trim().parse::<LocationID>().map_err(|_| err())?,
+                ))
             })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
             .unzip();
 
         let right_appearances =
@@ -68,12 +73,12 @@ impl FromStr for ListPair {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    Some(ListPair::from_str(input).ok()?.total_distance())
+fn part_one(input: &str) -> Result<u32, ParseListPairErr> {
+    Ok(ListPair::from_str(input)?.total_distance())
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    Some(ListPair::from_str(input).ok()?.similarity_score())
+fn part_two(input: &str) -> Result<u32, ParseListPairErr> {
+    Ok(ListPair::from_str(input)?.similarity_score())
 }
 
 #[cfg(test)]
@@ -83,12 +88,18 @@ mod tests {
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(11));
+        assert_eq!(result.unwrap(), 11);
     }
 
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(31));
+        assert_eq!(result.unwrap(), 31);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let result = part_one("3   4\n3   x\n");
+        assert_eq!(result.unwrap_err().to_string(), "invalid line `3   x`");
     }
 }
@@ -1,5 +1,5 @@
 use itertools::Itertools;
-use std::{cmp::Ordering, str::FromStr};
+use std::{cmp::Ordering, fmt::Display, str::FromStr};
 
 advent_of_code::solution!(2);
 
@@ -60,37 +60,44 @@ impl Report {
 }
 
 #[derive(Debug)]
-struct ParseReportErr;
+struct ParseReportErr(String);
+
+impl Display for ParseReportErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid level `{}`", self.0)
+    }
+}
 
 impl FromStr for Report {
     type Err = ParseReportErr;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let levels = s.split_whitespace().map(|i| i.parse().unwrap()).collect();
+        let levels = s
+            .split_whitespace()
+            .map(|i| i.parse().map_err(|_| ParseReportErr(i.to_string())))
+            .collect::<Result<_, _>>()?;
         Ok(Report { levels })
     }
 }
 
 type Level = i32;
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let num_safe_reports = input
+fn part_one(input: &str) -> Result<usize, ParseReportErr> {
+    let reports = input
         .lines()
-        .map(|l| Report::from_str(l).unwrap())
-        .filter(|r| r.is_safe())
-        .count();
+        .map(Report::from_str)
+        .collect::<Result<Vec<_>, _>>()?;
 
-    Some(num_safe_reports)
+    Ok(reports.iter().filter(|r| r.is_safe()).count())
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let num_safe_reports = input
+fn part_two(input: &str) -> Result<usize, ParseReportErr> {
+    let reports = input
         .lines()
-        .map(|l| Report::from_str(l).unwrap())
-        .filter(|r| r.dampened_is_safe())
-        .count();
+        .map(Report::from_str)
+        .collect::<Result<Vec<_>, _>>()?;
 
-    Some(num_safe_reports)
+    Ok(reports.iter().filter(|r| r.dampened_is_safe()).count())
 }
 
 #[cfg(test)]
@@ -100,12 +107,12 @@ mod tests {
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(2));
+        assert_eq!(result.unwrap(), 2);
     }
 
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4));
+        assert_eq!(result.unwrap(), 4);
     }
 }
@@ -6,7 +6,7 @@ use std::{
 
 use itertools::Itertools;
 
-advent_of_code::solution!(5);
+advent_of_code::solution!(5, parse = parse);
 
 #[derive(Debug)]
 struct SafetyManual {
@@ -79,9 +79,11 @@ impl FromStr for SafetyManual {
 
 type Page = usize;
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let sm = SafetyManual::from_str(input).expect("should be safety manual");
+fn parse(input: &str) -> SafetyManual {
+    SafetyManual::from_str(input).expect("should be safety manual")
+}
 
+fn part_one(sm: &SafetyManual) -> Option<u32> {
     let result = sm
         .updates
         .iter()
@@ -92,9 +94,7 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some(result)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let sm = SafetyManual::from_str(input).expect("should be safety manual");
-
+fn part_two(sm: &SafetyManual) -> Option<u32> {
     let result = sm
         .updates
         .iter()
@@ -112,13 +112,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(143));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(123));
     }
 }
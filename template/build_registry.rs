@@ -0,0 +1,42 @@
+/// Generates the list of solutions compiled into the `all` binary (see `src/template/registry.rs`).
+/// This is the build script of every year, so `CARGO_MANIFEST_DIR` points to the year being built.
+///
+/// Every `src/bin/<day>.rs` is included as a module, whose `SOLUTION` is then added to `SOLUTIONS`.
+use std::{env, fs, path::PathBuf};
+
+fn main() {
+    let bin_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
+        .join("src")
+        .join("bin");
+    println!("cargo:rerun-if-changed={}", bin_dir.display());
+
+    let mut days: Vec<String> = fs::read_dir(&bin_dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = path.file_stem()?.to_str()?;
+            let is_day = name.len() == 2 && name.bytes().all(|b| b.is_ascii_digit());
+            (is_day && path.extension()? == "rs").then(|| name.to_string())
+        })
+        .collect();
+    days.sort_unstable();
+
+    let mut registry = String::new();
+
+    for day in &days {
+        let path = bin_dir.join(format!("{day}.rs"));
+        // `main` of each solution is unused here.
+        registry.push_str(&format!(
+            "#[allow(dead_code)]\n#[path = {path:?}]\nmod day_{day};\n\n"
+        ));
+    }
+
+    registry.push_str("const SOLUTIONS: &[advent_of_code::template::registry::Solution] = &[\n");
+    for day in &days {
+        registry.push_str(&format!("    day_{day}::SOLUTION,\n"));
+    }
+    registry.push_str("];\n");
+
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join("solutions.rs");
+    fs::write(out_path, registry).unwrap();
+}
@@ -0,0 +1,4 @@
+// Summarizes every year in the readme at the root of the repository, see `advent_of_code::template::dashboard`.
+fn main() {
+    advent_of_code::template::commands::dashboard::handle();
+}
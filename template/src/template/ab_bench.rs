@@ -0,0 +1,348 @@
+/// A/B benchmarks of a day against another git revision, see `cargo time <day> --against <rev>`.
+///
+/// The revision is checked out into a temporary git worktree and built there. Both executables then run in alternating
+/// rounds on the same input, each round benching every step with the runner's sampling, so that a machine getting
+/// slower or faster over time affects both versions alike.
+use std::{
+    env, fs,
+    io::{stdout, Write},
+    path::{Path, PathBuf},
+    process::{self, Command},
+    time::Duration,
+};
+
+use crate::template::{
+    history::git,
+    limits::Limits,
+    report::{Record, Status, Step, REPORT_PATH_ENV},
+    run_multi::{child_commands, get_path_for_bin, Output},
+    runner::{BenchConfig, InputSource},
+    stats::{Ratio, Stats},
+    Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+
+/// Rounds each version runs unless set with `--rounds`.
+pub const DEFAULT_ROUNDS: u32 = 10;
+
+/// Settings of `cargo time <day> --against <rev>`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AbConfig {
+    pub rev: String,
+    pub rounds: u32,
+    pub input: InputSource,
+}
+
+/// A step benched in both versions.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StepComparison {
+    pub step: Step,
+    /// Median of the per-round runtimes of the revision.
+    pub before: Duration,
+    /// Median of the per-round runtimes of the working tree.
+    pub after: Duration,
+    /// Runtime of the revision divided by the runtime of the working tree, i.e. above 1 if the working tree is faster.
+    pub speedup: Ratio,
+    /// Whether both versions returned the same answer in every round.
+    pub answers_match: bool,
+}
+
+/// A temporary checkout of a revision, removed again when dropped.
+struct Worktree {
+    path: PathBuf,
+}
+
+impl Worktree {
+    fn add(commit: &str) -> Result<Self, String> {
+        let path = env::temp_dir().join(format!("aoc-against-{}", process::id()));
+
+        // a run that was killed leaves its worktree behind, which blocks a new one at a reused path.
+        let _ = git(&["worktree", "prune"]);
+        if path.exists() {
+            let _ = Command::new("git")
+                .args(["worktree", "remove", "--force"])
+                .arg(&path)
+                .status();
+            let _ = fs::remove_dir_all(&path);
+        }
+
+        let status = Command::new("git")
+            .args(["worktree", "add", "--detach", "--quiet"])
+            .arg(&path)
+            .arg(commit)
+            .status()
+            .map_err(|e| format!("could not run git: {e}"))?;
+
+        if !status.success() {
+            return Err(format!("could not check out {commit}."));
+        }
+        Ok(Self { path })
+    }
+}
+
+impl Drop for Worktree {
+    fn drop(&mut self) {
+        let _ = Command::new("git")
+            .args(["worktree", "remove", "--force"])
+            .arg(&self.path)
+            .status();
+    }
+}
+
+/// Benches a day of the working tree against the same day at `config.rev` and prints the speedup of every step.
+pub fn run(
+    day: Day,
+    config: &AbConfig,
+    bench: &BenchConfig,
+    limits: &Limits,
+) -> Result<(), String> {
+    if !Path::new(&get_path_for_bin(day)).exists() {
+        return Err(format!("day {day} has not been scaffolded yet."));
+    }
+
+    // NOTE: the year may live in a subdirectory of the repository, e.g. `2024/`.
+    let prefix = git(&["rev-parse", "--show-prefix"]).ok_or("not inside of a git repository.")?;
+    let commit = git(&[
+        "rev-parse",
+        "--short",
+        &format!("{}^{{commit}}", config.rev),
+    ])
+    .ok_or_else(|| format!("unknown revision `{}`.", config.rev))?;
+    let bin = day.to_string();
+
+    println!("Building day {day} of the working tree...");
+    let current = child_commands::build_bin(&bin, true, bench.alloc)
+        .ok()
+        .flatten()
+        .ok_or("failed to build the working tree.")?;
+
+    // builds of other revisions share a target directory, so that dependencies are only built once.
+    let target_dir = Path::new(&current)
+        .ancestors()
+        .nth(2)
+        .ok_or("unexpected location of the executable.")?
+        .join("against");
+
+    println!("Building day {day} at {commit}...");
+    let baseline = {
+        let worktree = Worktree::add(&commit)?;
+        child_commands::build_bin_in(
+            &bin,
+            true,
+            bench.alloc,
+            &worktree.path.join(prefix),
+            &target_dir,
+        )
+        .ok()
+        .flatten()
+        .ok_or_else(|| format!("failed to build day {day} at {commit}."))?
+    };
+
+    // split the budget between the rounds, so that the comparison takes about as long as benching both versions.
+    let round_bench = BenchConfig {
+        budget: bench.budget / config.rounds,
+        ..bench.clone()
+    };
+    let args = [round_bench.to_args(), config.input.to_args()].concat();
+    let run = |executable: &str| {
+        // NOTE: both executables run in the working tree, so they read the same input.
+        child_commands::run_executable(
+            executable,
+            &args,
+            day,
+            limits,
+            &mut Output::Buffer(String::new()),
+        )
+        .map_err(|e| format!("failed to run day {day}: {e:?}"))
+    };
+
+    let mut rounds = vec![];
+    for round in 0..config.rounds {
+        print!(
+            "\r{ANSI_ITALIC}Round {}/{}{ANSI_RESET}",
+            round + 1,
+            config.rounds
+        );
+        let _ = stdout().flush();
+
+        // alternate which version runs first, so that neither always runs on a warmer machine.
+        let pair = if round % 2 == 0 {
+            let before = run(&baseline)?;
+            (before, run(&current)?)
+        } else {
+            let after = run(&current)?;
+            (run(&baseline)?, after)
+        };
+        if is_too_old(&pair) {
+            println!();
+            return Err(format!(
+                "revision too old: day {day} at {commit} reported no results. Its runner predates the reports \
+                 written to `{REPORT_PATH_ENV}`, pick a newer revision."
+            ));
+        }
+        rounds.push(pair);
+    }
+    println!();
+
+    print_comparisons(day, &commit, config.rounds, &compare_rounds(&rounds));
+    Ok(())
+}
+
+/// Whether the revision reported nothing while the working tree did, i.e. its runner does not write reports yet.
+fn is_too_old((before, after): &(Vec<Record>, Vec<Record>)) -> bool {
+    before.is_empty() && !after.is_empty()
+}
+
+/// Pairs up the records of every round by step. Steps that did not succeed in both versions are left out of a round.
+fn compare_rounds(rounds: &[(Vec<Record>, Vec<Record>)]) -> Vec<StepComparison> {
+    let Some((_, first)) = rounds.first() else {
+        return vec![];
+    };
+
+    let successful = |records: &[Record], step: Step| {
+        records
+            .iter()
+            .find(|r| r.step == step && r.status == Status::Ok)
+            .cloned()
+    };
+
+    first
+        .iter()
+        .map(|record| record.step)
+        .filter_map(|step| {
+            let pairs: Vec<(Record, Record)> = rounds
+                .iter()
+                .filter_map(|(before, after)| {
+                    Some((successful(before, step)?, successful(after, step)?))
+                })
+                .collect();
+
+            #[allow(clippy::cast_precision_loss)]
+            let nanos: Vec<(f64, f64)> = pairs
+                .iter()
+                .map(|(b, a)| (b.duration_nanos as f64, a.duration_nanos as f64))
+                .collect();
+
+            Some(StepComparison {
+                step,
+                before: median(pairs.iter().map(|(b, _)| b.duration_nanos))?,
+                after: median(pairs.iter().map(|(_, a)| a.duration_nanos))?,
+                speedup: Ratio::from_pairs(&nanos)?,
+                answers_match: pairs.iter().all(|(b, a)| b.answer == a.answer),
+            })
+        })
+        .collect()
+}
+
+fn median(nanos: impl Iterator<Item = u128>) -> Option<Duration> {
+    #[allow(clippy::cast_possible_truncation)]
+    let samples: Vec<Duration> = nanos.map(|n| Duration::from_nanos(n as u64)).collect();
+    Stats::from_samples(&samples).map(|stats| stats.median)
+}
+
+fn print_comparisons(day: Day, commit: &str, rounds: u32, comparisons: &[StepComparison]) {
+    println!("\n{ANSI_BOLD}Day {day}: working tree vs {commit}{ANSI_RESET} ({rounds} rounds)");
+
+    if comparisons.is_empty() {
+        println!("No step succeeded in both versions.");
+        return;
+    }
+
+    for comparison in comparisons {
+        let StepComparison {
+            before,
+            after,
+            speedup,
+            ..
+        } = comparison;
+
+        let step = comparison.step.label();
+        let verdict = if speedup.low > 1.0 {
+            "faster"
+        } else if speedup.high < 1.0 {
+            "slower"
+        } else {
+            "no significant difference"
+        };
+
+        println!(
+            "{step}: {before:.1?} → {after:.1?} · speedup {:.2}× (95% CI {:.2}×–{:.2}×) · {verdict}",
+            speedup.estimate, speedup.low, speedup.high
+        );
+
+        if !comparison.answers_match {
+            println!("{ANSI_ITALIC}  the answers of both versions differ{ANSI_RESET}");
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{compare_rounds, is_too_old};
+    use crate::template::report::{Record, Status, Step};
+    use std::time::Duration;
+
+    fn record(step: Step, status: Status, duration_nanos: u128, answer: &str) -> Record {
+        Record {
+            step,
+            answer: Some(answer.into()),
+            duration_nanos,
+            samples: 10,
+            allocs: None,
+            status,
+            verified: None,
+            message: None,
+        }
+    }
+
+    fn round(before: [u128; 2], after: [u128; 2]) -> (Vec<Record>, Vec<Record>) {
+        let records = |nanos: [u128; 2]| {
+            vec![
+                record(Step::Part(1), Status::Ok, nanos[0], "1"),
+                record(Step::Part(2), Status::Ok, nanos[1], "2"),
+            ]
+        };
+        (records(before), records(after))
+    }
+
+    #[test]
+    fn detects_revisions_without_reports() {
+        let (_, after) = round([100, 100], [100, 100]);
+        assert_eq!(is_too_old(&(vec![], after)), true);
+        assert_eq!(is_too_old(&round([100, 100], [100, 100])), false);
+        // neither version reported anything, which is not the revision's fault.
+        assert_eq!(is_too_old(&(vec![], vec![])), false);
+    }
+
+    #[test]
+    fn compares_steps_over_rounds() {
+        let comparisons = compare_rounds(&[
+            round([200, 100], [100, 100]),
+            round([210, 100], [100, 100]),
+            round([190, 100], [100, 100]),
+        ]);
+
+        assert_eq!(comparisons.len(), 2);
+        assert_eq!(comparisons[0].step, Step::Part(1));
+        assert_eq!(comparisons[0].before, Duration::from_nanos(200));
+        assert_eq!(comparisons[0].after, Duration::from_nanos(100));
+        assert_eq!(comparisons[0].speedup.low > 1.5, true);
+        assert_eq!(comparisons[0].speedup.high < 2.5, true);
+        assert_eq!(comparisons[0].answers_match, true);
+        assert_eq!((comparisons[1].speedup.estimate - 1.0).abs() < 1e-9, true);
+    }
+
+    #[test]
+    fn skips_failed_steps() {
+        let mut failed = round([100, 100], [100, 100]);
+        failed.1[1].status = Status::Panicked;
+        failed.1[0].answer = Some("other".into());
+
+        let comparisons = compare_rounds(&[failed]);
+
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(comparisons[0].step, Step::Part(1));
+        assert_eq!(comparisons[0].answers_match, false);
+    }
+}
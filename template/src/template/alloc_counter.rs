@@ -0,0 +1,219 @@
+/// A lightweight global allocator that counts the allocations of a solution part.
+///
+/// With the `count-allocs` feature, the library installs [`CountingAlloc`] as the global allocator. It forwards to
+/// the system allocator and only starts counting inside [`measure`]. `--alloc` builds the solutions with the feature,
+/// every other binary, e.g. the CLI, allocates without it.
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    collections::HashMap,
+    sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering},
+};
+use tinyjson::JsonValue;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+// memory allocated before counting started can be freed while counting, so this may become negative.
+static LIVE: AtomicI64 = AtomicI64::new(0);
+static PEAK: AtomicI64 = AtomicI64::new(0);
+
+// `INSTALLED` must use the same predicate as this, or `--alloc` silently reports zero allocations.
+#[cfg(all(feature = "count-allocs", not(any(test, feature = "dhat-heap"))))]
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+/// Whether [`CountingAlloc`] is the global allocator, i.e. [`measure`] counts anything.
+pub const INSTALLED: bool = cfg!(all(
+    feature = "count-allocs",
+    not(any(test, feature = "dhat-heap"))
+));
+
+/// Allocations made while running a solution part.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations, including reallocations.
+    pub allocations: u64,
+    /// Total bytes requested by all allocations.
+    pub bytes: u64,
+    /// Largest number of bytes that were allocated at the same time.
+    pub peak_bytes: u64,
+}
+
+pub struct CountingAlloc;
+
+// SAFETY: all allocation requests are forwarded to the system allocator unchanged.
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+            record_alloc(layout.size(), layout.size() as i64);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        if ENABLED.load(Ordering::Relaxed) {
+            LIVE.fetch_sub(layout.size() as i64, Ordering::Relaxed);
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+            record_alloc(new_size, new_size as i64 - layout.size() as i64);
+        }
+        new_ptr
+    }
+}
+
+fn record_alloc(size: usize, live_delta: i64) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    BYTES.fetch_add(size as u64, Ordering::Relaxed);
+    let live = LIVE.fetch_add(live_delta, Ordering::Relaxed) + live_delta;
+    PEAK.fetch_max(live, Ordering::Relaxed);
+}
+
+/// Disables counting when dropped, so a panicking part does not leave counting on.
+struct Counting;
+
+impl Counting {
+    fn start() -> Self {
+        ALLOCATIONS.store(0, Ordering::Relaxed);
+        BYTES.store(0, Ordering::Relaxed);
+        LIVE.store(0, Ordering::Relaxed);
+        PEAK.store(0, Ordering::Relaxed);
+        ENABLED.store(true, Ordering::Relaxed);
+        Self
+    }
+}
+
+impl Drop for Counting {
+    fn drop(&mut self) {
+        ENABLED.store(false, Ordering::Relaxed);
+    }
+}
+
+/// Runs `func` and counts the allocations it makes.
+/// Returns empty stats if [`CountingAlloc`] is not the global allocator (see [`INSTALLED`]).
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, AllocStats) {
+    let counting = Counting::start();
+    let result = func();
+    drop(counting);
+
+    #[allow(clippy::cast_sign_loss)]
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+        bytes: BYTES.load(Ordering::Relaxed),
+        peak_bytes: PEAK.load(Ordering::Relaxed).max(0) as u64,
+    };
+
+    (result, stats)
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `1.5 KiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&AllocStats> for JsonValue {
+    fn from(value: &AllocStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        #[allow(clippy::cast_precision_loss)]
+        for (key, x) in [
+            ("allocations", value.allocations),
+            ("bytes", value.bytes),
+            ("peak_bytes", value.peak_bytes),
+        ] {
+            map.insert(key.into(), JsonValue::Number(x as f64));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for AllocStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected allocs to be a JSON object.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let get = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .map(|x| *x as u64)
+                .ok_or(format!("Expected allocs.{key} to be a number."))
+        };
+
+        Ok(AllocStats {
+            allocations: get("allocations")?,
+            bytes: get("bytes")?,
+            peak_bytes: get("peak_bytes")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_bytes, measure, AllocStats, CountingAlloc, ENABLED};
+    use std::{
+        alloc::{GlobalAlloc, Layout},
+        sync::atomic::Ordering,
+    };
+
+    #[test]
+    fn counts_allocations_while_measuring() {
+        let layout = Layout::from_size_align(64, 8).unwrap();
+
+        // the counting allocator is not installed in tests, so it is called directly.
+        let ((), stats) = measure(|| unsafe {
+            let a = CountingAlloc.alloc(layout);
+            let b = CountingAlloc.alloc(layout);
+            CountingAlloc.dealloc(a, layout);
+            let b = CountingAlloc.realloc(b, layout, 256);
+            CountingAlloc.dealloc(b, Layout::from_size_align(256, 8).unwrap());
+        });
+
+        assert_eq!(
+            stats,
+            AllocStats {
+                allocations: 3,
+                bytes: 384,
+                peak_bytes: 256,
+            }
+        );
+
+        // counting stops once `measure` returns, even if `func` panicked.
+        let _ = std::panic::catch_unwind(|| measure(|| -> u32 { panic!("oops") }));
+        assert_eq!(ENABLED.load(Ordering::Relaxed), false);
+    }
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
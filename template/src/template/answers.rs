@@ -0,0 +1,265 @@
+/// Known answers for solved puzzle parts, stored per day in `data/answers/DD.json`.
+/// Answers for a named puzzle input `data/inputs/DD/<name>.txt` are stored in `data/answers/DD/<name>.json`.
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::Day;
+
+/// Accepted answers for both parts of a day.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Answers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// Result of comparing a part's output against its known answer.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Verdict {
+    /// No answer has been recorded for this part yet.
+    Unknown,
+    Correct,
+    Wrong {
+        expected: String,
+    },
+}
+
+fn get_answers_path(day: Day, input: Option<&str>) -> PathBuf {
+    let answers = PathBuf::from("data").join("answers");
+    match input {
+        None => answers.join(format!("{day}.json")),
+        Some(name) => answers.join(day.to_string()).join(format!("{name}.json")),
+    }
+}
+
+impl Answers {
+    /// Reads the answers for a puzzle input of a day. If not present, returns empty answers.
+    /// A file that cannot be parsed is reported and treated as empty as well.
+    pub fn read(day: Day, input: Option<&str>) -> Self {
+        let path = get_answers_path(day, input);
+        Answers::read_from_path(&path).unwrap_or_else(|e| {
+            eprintln!("Failed to read \"{}\": {e}", path.display());
+            Answers::default()
+        })
+    }
+
+    /// Reads answers from a file, empty if it does not exist. Fails if the file cannot be read or parsed.
+    fn read_from_path(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(s) => Answers::try_from(s),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Writes the answers for a puzzle input of a day, creating `data/answers` if needed.
+    pub fn store(&self, day: Day, input: Option<&str>) -> Result<(), io::Error> {
+        let path = get_answers_path(day, input);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(path)?;
+        json.format_to(&mut file)
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: String) {
+        match part {
+            1 => self.part_1 = Some(answer),
+            2 => self.part_2 = Some(answer),
+            _ => {}
+        }
+    }
+
+    /// Compares a part's output against its known answer. Missing output never matches.
+    pub fn verify(&self, part: u8, actual: Option<&str>) -> Verdict {
+        match self.get(part) {
+            None => Verdict::Unknown,
+            Some(expected) if Some(expected) == actual => Verdict::Correct,
+            Some(expected) => Verdict::Wrong {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+/// Reads the answers of every day with a default puzzle input from a `data` directory, e.g. the one of another year.
+pub fn read_all(data_dir: &Path) -> Vec<(Day, Answers)> {
+    let Ok(entries) = fs::read_dir(data_dir.join("answers")) else {
+        return vec![];
+    };
+
+    let mut answers: Vec<(Day, Answers)> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let day = path.file_stem()?.to_str()?.parse().ok()?;
+            (path.extension()? == "json").then_some(())?;
+            let answers = fs::read_to_string(&path).ok()?.try_into().ok()?;
+            Some((day, answers))
+        })
+        .collect();
+
+    answers.sort_unstable_by_key(|(day, _)| *day);
+    answers
+}
+
+/// Records `answer` as the known answer for a part of a day and returns the path it was stored in.
+/// Refuses to overwrite a file that cannot be parsed, as that would lose the answer of the other part.
+pub fn accept(
+    day: Day,
+    input: Option<&str>,
+    part: u8,
+    answer: String,
+) -> Result<PathBuf, io::Error> {
+    let path = get_answers_path(day, input);
+    let mut answers = Answers::read_from_path(&path)
+        .map_err(|e| io::Error::other(format!("\"{}\" is invalid: {e}", path.display())))?;
+    answers.set(part, answer);
+    answers.store(day, input)?;
+    Ok(path)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answers> for JsonValue {
+    fn from(value: &Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        for (key, answer) in [("part_1", &value.part_1), ("part_2", &value.part_2)] {
+            map.insert(
+                key.into(),
+                match answer {
+                    Some(x) => JsonValue::String(x.clone()),
+                    None => JsonValue::Null,
+                },
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let get_part = |key: &str| -> Result<Option<String>, String> {
+            match json.get(key) {
+                None | Some(JsonValue::Null) => Ok(None),
+                Some(JsonValue::String(s)) => Ok(Some(s.clone())),
+                Some(_) => Err(format!("expected `{key}` to be null or string.")),
+            }
+        };
+
+        Ok(Answers {
+            part_1: get_part("part_1")?,
+            part_2: get_part("part_2")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{get_answers_path, Answers, Verdict};
+    use crate::day;
+    use std::{env, fs, path::PathBuf, process};
+    use tinyjson::JsonValue;
+
+    #[test]
+    fn stores_named_inputs_separately() {
+        assert_eq!(
+            get_answers_path(day!(5), None),
+            PathBuf::from("data/answers/05.json")
+        );
+        assert_eq!(
+            get_answers_path(day!(5), Some("alice")),
+            PathBuf::from("data/answers/05/alice.json")
+        );
+    }
+
+    #[test]
+    fn handles_json_answers() {
+        let json = r#"{ "part_1": "143", "part_2": null }"#.to_string();
+        let answers = Answers::try_from(json).unwrap();
+        assert_eq!(answers.part_1, Some("143".into()));
+        assert_eq!(answers.part_2, None);
+    }
+
+    #[test]
+    fn handles_missing_keys() {
+        let answers = Answers::try_from("{}".to_string()).unwrap();
+        assert_eq!(answers, Answers::default());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_numeric_answers() {
+        Answers::try_from(r#"{ "part_1": 143 }"#.to_string()).unwrap();
+    }
+
+    #[test]
+    fn reads_missing_and_invalid_files() {
+        let dir = env::temp_dir().join(format!("aoc-answers-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("05.json");
+
+        assert_eq!(Answers::read_from_path(&path), Ok(Answers::default()));
+
+        fs::write(&path, r#"{ "part_1": "143", "part_2": "#).unwrap();
+        assert_eq!(Answers::read_from_path(&path).is_err(), true);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn round_trips_answers() {
+        let answers = Answers {
+            part_1: Some("1".into()),
+            part_2: Some("line 1\nline 2".into()),
+        };
+        let json = JsonValue::from(&answers).stringify().unwrap();
+        assert_eq!(Answers::try_from(json).unwrap(), answers);
+    }
+
+    #[test]
+    fn verifies_answers() {
+        let answers = Answers {
+            part_1: Some("143".into()),
+            part_2: None,
+        };
+        assert_eq!(answers.verify(1, Some("143")), Verdict::Correct);
+        assert_eq!(
+            answers.verify(1, Some("144")),
+            Verdict::Wrong {
+                expected: "143".into()
+            }
+        );
+        assert_eq!(
+            answers.verify(1, None),
+            Verdict::Wrong {
+                expected: "143".into()
+            }
+        );
+        assert_eq!(answers.verify(2, Some("123")), Verdict::Unknown);
+    }
+}
@@ -0,0 +1,111 @@
+/// Offline backend serving puzzles, inputs and verdicts from a local directory, laid out like `data`:
+///
+/// - `puzzles/DD.md` and `inputs/DD.txt` are returned as they are.
+/// - `answers/DD.json` holds the correct answers, in the same format as `data/answers`. Submissions are
+///   compared against them and answered with the same wording as the website.
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::template::{
+    answers::Answers,
+    aoc_client::{AocClient, AocClientError},
+    Day,
+};
+
+pub const FIXTURES_ENV: &str = "AOC_FIXTURES";
+
+pub struct FixtureClient {
+    dir: PathBuf,
+}
+
+impl FixtureClient {
+    pub fn new(dir: &Path) -> Self {
+        Self {
+            dir: dir.to_path_buf(),
+        }
+    }
+
+    fn read(&self, path: PathBuf) -> Result<String, AocClientError> {
+        fs::read_to_string(&path).map_err(|_| AocClientError::MissingFixture(path))
+    }
+}
+
+impl AocClient for FixtureClient {
+    fn puzzle(&self, day: Day) -> Result<String, AocClientError> {
+        self.read(self.dir.join("puzzles").join(format!("{day}.md")))
+    }
+
+    fn input(&self, day: Day) -> Result<String, AocClientError> {
+        self.read(self.dir.join("inputs").join(format!("{day}.txt")))
+    }
+
+    fn submit(&self, day: Day, part: u8, answer: &str) -> Result<String, AocClientError> {
+        let path = self.dir.join("answers").join(format!("{day}.json"));
+        let answers = Answers::try_from(self.read(path.clone())?)
+            .map_err(|_| AocClientError::MissingFixture(path.clone()))?;
+        let expected = answers
+            .get(part)
+            .ok_or(AocClientError::MissingFixture(path))?;
+
+        Ok(respond(expected, answer))
+    }
+}
+
+fn respond(expected: &str, answer: &str) -> String {
+    if answer == expected {
+        return "That's the right answer! You are one gold star closer to finishing.".into();
+    }
+
+    let hint = match (answer.parse::<i64>(), expected.parse::<i64>()) {
+        (Ok(answer), Ok(expected)) if answer > expected => "; your answer is too high",
+        (Ok(answer), Ok(expected)) if answer < expected => "; your answer is too low",
+        _ => "",
+    };
+
+    format!("That's not the right answer{hint}. Please wait one minute before trying again.")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::FixtureClient;
+    use crate::day;
+    use crate::template::{
+        aoc_client::{AocClient, AocClientError},
+        submissions::{parse_response, Verdict},
+    };
+    use std::{env, fs, process};
+
+    #[test]
+    fn serves_fixtures() {
+        let dir = env::temp_dir().join(format!("aoc-fixtures-{}", process::id()));
+        fs::create_dir_all(dir.join("inputs")).unwrap();
+        fs::create_dir_all(dir.join("answers")).unwrap();
+        fs::write(dir.join("inputs").join("07.txt"), "1\n2\n").unwrap();
+        fs::write(
+            dir.join("answers").join("07.json"),
+            r#"{ "part_1": "3", "part_2": null }"#,
+        )
+        .unwrap();
+
+        let client = FixtureClient::new(&dir);
+
+        assert_eq!(client.input(day!(7)).unwrap(), "1\n2\n");
+        assert!(matches!(
+            client.puzzle(day!(7)),
+            Err(AocClientError::MissingFixture(_))
+        ));
+
+        let verdict = |answer| parse_response(&client.submit(day!(7), 1, answer).unwrap());
+        assert_eq!(verdict("3"), (Verdict::Correct, None));
+        assert_eq!(verdict("4"), (Verdict::TooHigh, Some(60)));
+        assert_eq!(verdict("2"), (Verdict::TooLow, Some(60)));
+        assert_eq!(verdict("x"), (Verdict::Wrong, Some(60)));
+        assert!(client.submit(day!(7), 2, "3").is_err());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,335 @@
+/// Backend talking to the Advent of Code website.
+///
+/// Requests are spaced at least [`MIN_REQUEST_INTERVAL`] apart, and inputs are cached in `data/cache`, per session.
+/// Setting `AOC_BASE_URL` points the client at another server, e.g. a local stand-in for testing.
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::{
+    aoc_client::{AocClient, AocClientError},
+    html, Day, Year,
+};
+
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub(super) const SESSION_FILE: &str = ".adventofcode.session";
+const USER_AGENT: &str = concat!(
+    "github.com/connor15mcc/aoc2025 advent_of_code/",
+    env!("CARGO_PKG_VERSION")
+);
+
+/// Minimum time between two requests, also across processes.
+pub const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(1);
+
+pub struct HttpClient {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    year: Year,
+    cache_dir: PathBuf,
+}
+
+impl HttpClient {
+    pub fn new(base_url: &str, session: &str, year: Year, cache_dir: &Path) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            year,
+            cache_dir: cache_dir.to_path_buf(),
+        }
+    }
+
+    /// Reads the year from `AOC_YEAR`, the session cookie from `AOC_SESSION` or `~/.adventofcode.session`
+    /// and the server from `AOC_BASE_URL`.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let year = Year::from_env().ok_or(AocClientError::MissingYear)?;
+        let session = read_session().ok_or(AocClientError::MissingSession)?;
+        let base_url = env::var(BASE_URL_ENV).unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+
+        Ok(Self::new(
+            &base_url,
+            &session,
+            year,
+            &PathBuf::from("data").join("cache"),
+        ))
+    }
+
+    fn get(&self, path: &str) -> ureq::Request {
+        self.agent
+            .get(&format!("{}{path}", self.base_url))
+            .set("Cookie", &format!("session={}", self.session))
+    }
+
+    fn request(&self, request: ureq::Request) -> Result<String, AocClientError> {
+        self.throttle();
+        Ok(request.call()?.into_string()?)
+    }
+
+    /// Every account gets its own input, so the cache is kept apart per session.
+    fn cache_path(&self, name: &str) -> PathBuf {
+        self.cache_dir
+            .join(self.year.to_string())
+            .join(session_key(&self.session))
+            .join(name)
+    }
+
+    fn cached(
+        &self,
+        name: &str,
+        fetch: impl FnOnce() -> Result<String, AocClientError>,
+    ) -> Result<String, AocClientError> {
+        let path = self.cache_path(name);
+
+        if let Ok(cached) = fs::read_to_string(&path) {
+            return Ok(cached);
+        }
+
+        let content = fetch()?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&path, &content)?;
+        Ok(content)
+    }
+
+    /// Waits until [`MIN_REQUEST_INTERVAL`] passed since the last request, which is tracked in the cache directory.
+    fn throttle(&self) {
+        let marker = self.cache_dir.join("last-request");
+
+        let elapsed = fs::metadata(&marker)
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|modified| SystemTime::now().duration_since(modified).ok());
+
+        if let Some(wait) = elapsed.and_then(|elapsed| MIN_REQUEST_INTERVAL.checked_sub(elapsed)) {
+            thread::sleep(wait);
+        }
+
+        let _ = fs::create_dir_all(&self.cache_dir);
+        let _ = fs::write(&marker, "");
+    }
+}
+
+impl AocClient for HttpClient {
+    /// Never cached, as solving part one reveals part two, also when it is solved in the browser.
+    fn puzzle(&self, day: Day) -> Result<String, AocClientError> {
+        let html = self.request(self.get(&format!("/{}/day/{}", self.year, day.into_inner())))?;
+        Ok(html::to_markdown(&html))
+    }
+
+    /// Inputs never change, so they are cached for good.
+    fn input(&self, day: Day) -> Result<String, AocClientError> {
+        self.cached(&format!("{day}.txt"), || {
+            self.request(self.get(&format!("/{}/day/{}/input", self.year, day.into_inner())))
+        })
+    }
+
+    fn submit(&self, day: Day, part: u8, answer: &str) -> Result<String, AocClientError> {
+        let path = format!("/{}/day/{}/answer", self.year, day.into_inner());
+        let request = self.agent.post(&format!("{}{path}", self.base_url));
+        let request = request.set("Cookie", &format!("session={}", self.session));
+
+        self.throttle();
+        let html = request
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?
+            .into_string()?;
+
+        Ok(html::article_text(&html))
+    }
+}
+
+/// Names the cache of a session without writing the session itself to disk: its FNV-1a hash, in hex.
+fn session_key(session: &str) -> String {
+    let hash = session
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+    format!("{hash:016x}")
+}
+
+/// Reads the session cookie from `AOC_SESSION` or `~/.adventofcode.session`.
+fn read_session() -> Option<String> {
+    let session = env::var(SESSION_ENV).ok().or_else(|| {
+        let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+        fs::read_to_string(PathBuf::from(home).join(SESSION_FILE)).ok()
+    })?;
+
+    let session = session.trim();
+    (!session.is_empty()).then(|| session.to_string())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{HttpClient, USER_AGENT};
+    use crate::day;
+    use crate::template::{
+        aoc_client::{AocClient, AocClientError},
+        Year,
+    };
+    use std::{
+        env, fs,
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        path::PathBuf,
+        process,
+        sync::mpsc,
+        thread,
+    };
+
+    /// Serves a single request with `status` and `body` on a local port and sends the raw request back.
+    fn serve_once(status: &'static str, body: &'static str) -> (String, mpsc::Receiver<String>) {
+        serve(vec![(status, body)])
+    }
+
+    /// Serves one request per response on a local port, in order, and sends the raw requests back.
+    fn serve(responses: Vec<(&'static str, &'static str)>) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = length.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+                let mut content = vec![0; content_length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8_lossy(&content));
+
+                let response = format!(
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+                tx.send(request).unwrap();
+            }
+        });
+
+        (base_url, rx)
+    }
+
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-client-{name}-{}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn fetches_and_caches_inputs() {
+        let (base_url, requests) = serve_once("200 OK", "1 2\n3 4\n");
+        let cache = cache_dir("input");
+        let client = HttpClient::new(&base_url, "secret", Year::new(2024).unwrap(), &cache);
+
+        assert_eq!(client.input(day!(1)).unwrap(), "1 2\n3 4\n");
+
+        let request = requests.recv().unwrap();
+        assert_eq!(request.starts_with("GET /2024/day/1/input "), true);
+        assert_eq!(request.contains("session=secret"), true);
+        assert_eq!(request.contains(USER_AGENT), true);
+
+        // the server only answers once, so this is served from the cache.
+        assert_eq!(client.input(day!(1)).unwrap(), "1 2\n3 4\n");
+        let _ = fs::remove_dir_all(&cache);
+    }
+
+    #[test]
+    fn caches_inputs_per_session() {
+        let (base_url, requests) = serve(vec![("200 OK", "alice\n"), ("200 OK", "bob\n")]);
+        let cache = cache_dir("session");
+        let year = Year::new(2024).unwrap();
+        let alice = HttpClient::new(&base_url, "alice", year, &cache);
+        let bob = HttpClient::new(&base_url, "bob", year, &cache);
+
+        assert_eq!(alice.input(day!(1)).unwrap(), "alice\n");
+        // another account has another input, so it must not be served alice's.
+        assert_eq!(bob.input(day!(1)).unwrap(), "bob\n");
+        assert_eq!(alice.input(day!(1)).unwrap(), "alice\n");
+
+        assert_eq!(requests.recv().unwrap().contains("session=alice"), true);
+        assert_eq!(requests.recv().unwrap().contains("session=bob"), true);
+        let _ = fs::remove_dir_all(&cache);
+    }
+
+    #[test]
+    fn refetches_puzzles() {
+        let (base_url, requests) = serve(vec![
+            ("200 OK", "<main><article><p>Part one.</p></article></main>"),
+            (
+                "200 OK",
+                "<main><article><p>Part one.</p></article><article><p>Part two.</p></article></main>",
+            ),
+        ]);
+        let cache = cache_dir("puzzle");
+        let client = HttpClient::new(&base_url, "secret", Year::new(2024).unwrap(), &cache);
+
+        assert_eq!(client.puzzle(day!(3)).unwrap().contains("Part two."), false);
+        // part one got solved in the browser, so `read` and `refresh-tests` must see part two.
+        assert_eq!(client.puzzle(day!(3)).unwrap().contains("Part two."), true);
+
+        assert_eq!(
+            requests.recv().unwrap().starts_with("GET /2024/day/3 "),
+            true
+        );
+        assert_eq!(
+            requests.recv().unwrap().starts_with("GET /2024/day/3 "),
+            true
+        );
+        let _ = fs::remove_dir_all(&cache);
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (base_url, requests) = serve_once(
+            "200 OK",
+            "<main><article><p>That's the right answer!</p></article></main>",
+        );
+        let cache = cache_dir("submit");
+        let client = HttpClient::new(&base_url, "secret", Year::new(2024).unwrap(), &cache);
+
+        assert_eq!(
+            client.submit(day!(5), 2, "143").unwrap(),
+            "That's the right answer!"
+        );
+
+        let request = requests.recv().unwrap();
+        assert_eq!(request.starts_with("POST /2024/day/5/answer "), true);
+        assert_eq!(request.ends_with("level=2&answer=143"), true);
+        let _ = fs::remove_dir_all(&cache);
+    }
+
+    #[test]
+    fn reports_error_status() {
+        let (base_url, _requests) = serve_once("404 Not Found", "");
+        let cache = cache_dir("status");
+        let client = HttpClient::new(&base_url, "secret", Year::new(2024).unwrap(), &cache);
+
+        assert!(matches!(
+            client.puzzle(day!(25)),
+            Err(AocClientError::Status(404))
+        ));
+        let _ = fs::remove_dir_all(&cache);
+    }
+}
@@ -0,0 +1,293 @@
+/// Access to Advent of Code puzzles, used to read puzzles, download inputs and submit answers.
+///
+/// The commands use the website via [`http::HttpClient`], or the local fixtures of [`fixture::FixtureClient`]
+/// if `AOC_FIXTURES` is set to a directory.
+use std::{env, fmt::Display, fs, io, path::Path, path::PathBuf};
+
+use crate::template::{
+    examples::{self, Selection},
+    submissions::{self, History, Submission},
+    Day,
+};
+
+pub mod fixture;
+pub mod http;
+
+/// A source of puzzles that answers can be submitted to.
+pub trait AocClient {
+    /// Fetches the description of a puzzle as markdown.
+    fn puzzle(&self, day: Day) -> Result<String, AocClientError>;
+
+    /// Fetches the puzzle input of a day.
+    fn input(&self, day: Day) -> Result<String, AocClientError>;
+
+    /// Submits an answer and returns the text of the response.
+    fn submit(&self, day: Day, part: u8, answer: &str) -> Result<String, AocClientError>;
+}
+
+#[derive(Debug)]
+pub enum AocClientError {
+    /// Neither `AOC_SESSION` nor `~/.adventofcode.session` is set.
+    MissingSession,
+    /// `AOC_YEAR` is not set.
+    MissingYear,
+    /// The server responded with an error status.
+    Status(u16),
+    Transport(String),
+    IO(io::Error),
+    /// The fixture backend has no usable file for the request.
+    MissingFixture(PathBuf),
+    /// The answer was not submitted because previous submissions show that it is wrong.
+    Refused(String),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::MissingSession => write!(
+                f,
+                "no session cookie found, set `{}` or create `~/{}`.",
+                http::SESSION_ENV,
+                http::SESSION_FILE
+            ),
+            AocClientError::MissingYear => write!(f, "`AOC_YEAR` is not set."),
+            AocClientError::Status(status) => {
+                write!(f, "the server responded with status {status}.")
+            }
+            AocClientError::Transport(e) => write!(f, "request failed: {e}"),
+            AocClientError::IO(e) => write!(f, "{e}"),
+            AocClientError::MissingFixture(path) => {
+                write!(f, "missing or invalid fixture \"{}\".", path.display())
+            }
+            AocClientError::Refused(reason) => write!(f, "refusing to submit: {reason}"),
+        }
+    }
+}
+
+impl AocClientError {
+    /// Returns whether the server refused the request because the puzzle has not unlocked yet.
+    pub fn is_locked(&self) -> bool {
+        matches!(self, AocClientError::Status(404))
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, _) => AocClientError::Status(status),
+            ureq::Error::Transport(e) => AocClientError::Transport(e.to_string()),
+        }
+    }
+}
+
+/// Picks the backend from the environment: the fixtures in `AOC_FIXTURES` if set, otherwise the website.
+pub fn from_env() -> Result<Box<dyn AocClient>, AocClientError> {
+    match env::var_os(fixture::FIXTURES_ENV) {
+        Some(dir) => Ok(Box::new(fixture::FixtureClient::new(Path::new(&dir)))),
+        None => Ok(Box::new(http::HttpClient::from_env()?)),
+    }
+}
+
+/// Prints the description of a puzzle and stores it in `data/puzzles`, along with its examples.
+pub fn read(day: Day, selection: &Selection) -> Result<(), AocClientError> {
+    let puzzle = from_env()?.puzzle(day)?;
+    write_file(&get_puzzle_path(day), &puzzle)?;
+    println!("{puzzle}");
+    examples::write(day, &puzzle, selection);
+    Ok(())
+}
+
+/// Stores the description of a puzzle in `data/puzzles` without printing it, along with its examples.
+pub fn update_puzzle(day: Day, selection: &Selection) -> Result<(), AocClientError> {
+    let puzzle = from_env()?.puzzle(day)?;
+    write_file(&get_puzzle_path(day), &puzzle)?;
+    examples::write(day, &puzzle, selection);
+    Ok(())
+}
+
+/// Downloads the input and the description of a puzzle to `data/inputs` and `data/puzzles`,
+/// and extracts its examples to `data/examples`.
+pub fn download(day: Day, selection: &Selection) -> Result<(), AocClientError> {
+    let client = from_env()?;
+    let input_path = get_input_path(day);
+    let puzzle_path = get_puzzle_path(day);
+    let puzzle = client.puzzle(day)?;
+
+    write_file(&input_path, &client.input(day)?)?;
+    write_file(&puzzle_path, &puzzle)?;
+
+    println!("---");
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    examples::write(day, &puzzle, selection);
+    Ok(())
+}
+
+/// Submits an answer unless the submission history of the day shows that it is wrong, and records the verdict.
+pub fn submit(day: Day, part: u8, result: &str) -> Result<Submission, AocClientError> {
+    let mut history = History::read(day).map_err(AocClientError::Refused)?;
+    history
+        .check(part, result)
+        .map_err(AocClientError::Refused)?;
+
+    let now = submissions::now();
+    if let Some(seconds) = history.cooldown(now) {
+        eprintln!(
+            "Warning: the previous answer asked to wait another {seconds}s before submitting again."
+        );
+    }
+
+    let response = from_env()?.submit(day, part, result)?;
+    println!("{response}");
+
+    let (verdict, wait) = submissions::parse_response(&response);
+    let submission = Submission {
+        part,
+        answer: result.to_string(),
+        timestamp: now,
+        verdict,
+        wait,
+    };
+
+    history.push(submission.clone());
+    if let Err(e) = history.store(day) {
+        eprintln!("Failed to record submission: {e}");
+    }
+
+    Ok(submission)
+}
+
+fn write_file(path: &str, content: &str) -> Result<(), io::Error> {
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, content)
+}
+
+fn get_input_path(day: Day) -> String {
+    format!("data/inputs/{day}.txt")
+}
+
+fn get_puzzle_path(day: Day) -> String {
+    format!("data/puzzles/{day}.md")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{fixture::FIXTURES_ENV, submit, AocClientError};
+    use crate::day;
+    use crate::template::{
+        commands,
+        examples::Selection,
+        read_file,
+        report::{self, Status, Step, REPORT_PATH_ENV},
+        runner::{read_input, run_part},
+        submissions::Verdict,
+    };
+    use std::{env, fs, path::Path, process};
+
+    /// Set in the child process that runs the flow of [`scaffold_download_solve_submit`].
+    const CHILD_ENV: &str = "AOC_END_TO_END_CHILD";
+
+    /// The solution as it would be filled into the scaffolded `src/bin/03.rs`.
+    fn part_one(input: &str) -> Option<u32> {
+        Some(input.lines().map(|x| x.parse::<u32>().unwrap()).sum())
+    }
+
+    /// Runs the command flow of a day against fixtures, in a scratch project directory.
+    /// The flow changes the working directory and the environment, so it runs in a child process of the test binary
+    /// that leaves the other tests alone.
+    #[test]
+    fn scaffold_download_solve_submit() {
+        if env::var_os(CHILD_ENV).is_some() {
+            return run_flow();
+        }
+
+        let root = env::temp_dir().join(format!("aoc-end-to-end-{}", process::id()));
+        let fixtures = root.join("fixtures");
+        let project = root.join("project");
+        let _ = fs::remove_dir_all(&root);
+
+        for dir in ["puzzles", "inputs", "answers"] {
+            fs::create_dir_all(fixtures.join(dir)).unwrap();
+        }
+        fs::write(fixtures.join("puzzles/03.md"), PUZZLE).unwrap();
+        fs::write(fixtures.join("inputs/03.txt"), "1\n2\n3\n").unwrap();
+        fs::write(
+            fixtures.join("answers/03.json"),
+            r#"{ "part_1": "6", "part_2": null }"#,
+        )
+        .unwrap();
+
+        for dir in ["src/bin", "data/inputs", "data/examples"] {
+            fs::create_dir_all(project.join(dir)).unwrap();
+        }
+
+        let (_, test) = module_path!().split_once("::").unwrap();
+        let status = process::Command::new(env::current_exe().unwrap())
+            .args([
+                "--exact",
+                &format!("{test}::scaffold_download_solve_submit"),
+                "--nocapture",
+            ])
+            .current_dir(&project)
+            .env(CHILD_ENV, "1")
+            .env(FIXTURES_ENV, &fixtures)
+            .env(REPORT_PATH_ENV, root.join("report.jsonl"))
+            .status()
+            .unwrap();
+
+        let _ = fs::remove_dir_all(&root);
+        assert_eq!(status.success(), true);
+    }
+
+    const PUZZLE: &str =
+        "## --- Day 3 ---\n\nFor example:\n\n```\n1\n2\n```\n\nThe sum is `*3*`.\n";
+
+    fn run_flow() {
+        commands::scaffold::handle(day!(3), false);
+        assert_eq!(Path::new("src/bin/03.rs").exists(), true);
+
+        commands::download::handle(day!(3), &Selection::default());
+        assert_eq!(fs::read_to_string("data/puzzles/03.md").unwrap(), PUZZLE);
+        assert_eq!(
+            fs::read_to_string("data/examples/03.txt").unwrap(),
+            "1\n2\n"
+        );
+        assert_eq!(commands::scaffold::fill_tests(day!(3)), true);
+        assert_eq!(
+            fs::read_to_string("src/bin/03.rs")
+                .unwrap()
+                .contains("assert_eq!(result, Some(3));"),
+            true
+        );
+
+        // the filled in test of the scaffolded day, then the solution as `solution!` runs it.
+        assert_eq!(part_one(&read_file("examples", day!(3))), Some(3));
+        run_part(part_one, read_input(day!(3), 1).as_str(), day!(3), 1);
+
+        let records = report::read(Path::new(&env::var(REPORT_PATH_ENV).unwrap())).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].step, Step::Part(1));
+        assert_eq!(records[0].status, Status::Ok);
+        let answer: u32 = records[0].answer.as_deref().unwrap().parse().unwrap();
+
+        let wrong = submit(day!(3), 1, &(answer + 1).to_string()).unwrap();
+        assert_eq!(wrong.verdict, Verdict::TooHigh);
+        assert!(matches!(
+            submit(day!(3), 1, &(answer + 2).to_string()),
+            Err(AocClientError::Refused(_))
+        ));
+        let right = submit(day!(3), 1, &answer.to_string()).unwrap();
+        assert_eq!(right.verdict, Verdict::Correct);
+        assert_eq!(Path::new("data/submissions/03.json").exists(), true);
+    }
+}
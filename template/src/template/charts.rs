@@ -0,0 +1,336 @@
+/// SVG charts of the timings, embedded in the benchmarks block of the readme.
+///
+/// The charts are plain SVG built from strings, so no external tools are needed:
+///  1. a bar chart of the runtime of part one and two of every day, on a log scale.
+///  2. a sparkline of the total runtime of every day over the stored runs, once there are at least two.
+use std::{fmt::Write, fs, io, path::Path};
+
+use crate::template::{
+    report::{format_nanos, Step},
+    timings::Timings,
+    Day,
+};
+
+/// Directory the charts are written to, relative to the year.
+pub const CHARTS_DIR: &str = "./data/charts";
+
+const RUNTIMES_FILE: &str = "runtimes.svg";
+const HISTORY_FILE: &str = "history.svg";
+
+const PART_COLORS: [&str; 2] = ["#4e79a7", "#f28e2b"];
+const FASTER_COLOR: &str = "#59a14f";
+const SLOWER_COLOR: &str = "#e15759";
+const FONT: &str = r##"font-family="sans-serif" font-size="11" fill="#444""##;
+
+/* -------------------------------------------------------------------------- */
+
+/// Writes every chart that has data to `dir` and returns the markdown images to embed them, e.g. in the readme.
+pub fn write_all(timings: &Timings, dir: &Path) -> Result<Vec<String>, io::Error> {
+    let charts = [
+        (RUNTIMES_FILE, "Runtime per day", runtime_chart(timings)),
+        (HISTORY_FILE, "Runtime over time", history_chart(timings)),
+    ];
+
+    let mut images = vec![];
+    for (file, alt, svg) in charts {
+        let Some(svg) = svg else {
+            continue;
+        };
+
+        fs::create_dir_all(dir)?;
+        fs::write(dir.join(file), svg)?;
+        images.push(format!("![{alt}]({}/{file})", dir.display()));
+    }
+
+    Ok(images)
+}
+
+/* -------------------------------------------------------------------------- */
+
+const BAR_WIDTH: f64 = 10.0;
+const DAY_WIDTH: f64 = 2.0 * BAR_WIDTH + 10.0;
+const AXIS_WIDTH: f64 = 50.0;
+const PLOT_TOP: f64 = 30.0;
+const PLOT_HEIGHT: f64 = 160.0;
+const LEGEND_WIDTH: f64 = AXIS_WIDTH + 140.0;
+
+/// Powers of ten enclosing all values, as exponents.
+fn decades(values: impl Iterator<Item = f64>) -> Option<(i32, i32)> {
+    let (min, max) = values.fold(None, |range: Option<(f64, f64)>, v| {
+        Some(range.map_or((v, v), |(min, max)| (min.min(v), max.max(v))))
+    })?;
+
+    #[allow(clippy::cast_possible_truncation)]
+    let (low, high) = (min.log10().floor() as i32, max.log10().ceil() as i32);
+    Some((low, high.max(low + 1)))
+}
+
+fn format_decade(exponent: i32) -> String {
+    const UNITS: [&str; 4] = ["ns", "µs", "ms", "s"];
+
+    let unit = usize::try_from(exponent.div_euclid(3)).map_or(0, |u| u.min(UNITS.len() - 1));
+    let value = 10_f64.powi(exponent - 3 * i32::try_from(unit).unwrap_or_default());
+    format!("{value}{}", UNITS[unit])
+}
+
+/// Bar chart of part one and two of every day, on a log scale. [`None`] if no day has a runtime.
+pub fn runtime_chart(timings: &Timings) -> Option<String> {
+    let days: Vec<(Day, [Option<f64>; 2])> = timings
+        .data
+        .iter()
+        .map(|t| {
+            let parts = [1, 2].map(|part| t.step_nanos(Step::Part(part)));
+            (t.day, parts)
+        })
+        .filter(|(_, parts)| parts.iter().any(Option::is_some))
+        .collect();
+
+    let (low, high) = decades(
+        days.iter()
+            .flat_map(|(_, parts)| parts.iter().flatten().copied()),
+    )?;
+    let y = |nanos: f64| {
+        let share = (nanos.log10() - f64::from(low)) / f64::from(high - low);
+        PLOT_TOP + PLOT_HEIGHT * (1.0 - share)
+    };
+
+    #[allow(clippy::cast_precision_loss)]
+    let width = (AXIS_WIDTH + DAY_WIDTH * days.len() as f64 + 10.0).max(LEGEND_WIDTH);
+    let height = PLOT_TOP + PLOT_HEIGHT + 30.0;
+
+    let mut svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+    );
+    let _ = write!(svg, r#"<rect width="100%" height="100%" fill="white"/>"#);
+
+    // legend
+    for (i, color) in PART_COLORS.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let x = AXIS_WIDTH + 70.0 * i as f64;
+        let _ = write!(
+            svg,
+            r#"<rect x="{x}" y="8" width="10" height="10" fill="{color}"/><text x="{}" y="17" {FONT}>Part {}</text>"#,
+            x + 14.0,
+            i + 1
+        );
+    }
+
+    // axis with a grid line per power of ten
+    for exponent in low..=high {
+        let y = y(10_f64.powi(exponent));
+        let _ = write!(
+            svg,
+            r##"<line x1="{AXIS_WIDTH}" x2="{}" y1="{y}" y2="{y}" stroke="#ddd"/><text x="{}" y="{}" text-anchor="end" {FONT}>{}</text>"##,
+            width - 10.0,
+            AXIS_WIDTH - 4.0,
+            y + 4.0,
+            format_decade(exponent)
+        );
+    }
+
+    for (i, (day, parts)) in days.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let x = AXIS_WIDTH + DAY_WIDTH * i as f64 + 5.0;
+
+        for (part, nanos) in parts.iter().enumerate() {
+            let Some(nanos) = nanos else {
+                continue;
+            };
+            let top = y(*nanos).min(PLOT_TOP + PLOT_HEIGHT - 1.0);
+            let _ = write!(
+                svg,
+                r#"<rect x="{}" y="{top:.1}" width="{BAR_WIDTH}" height="{:.1}" fill="{}"><title>Day {day} Part {}: {}</title></rect>"#,
+                x + BAR_WIDTH * part as f64,
+                PLOT_TOP + PLOT_HEIGHT - top,
+                PART_COLORS[part],
+                part + 1,
+                format_nanos(*nanos)
+            );
+        }
+
+        let _ = write!(
+            svg,
+            r#"<text x="{}" y="{}" text-anchor="middle" {FONT}>{day}</text>"#,
+            x + BAR_WIDTH,
+            PLOT_TOP + PLOT_HEIGHT + 16.0
+        );
+    }
+
+    svg.push_str("</svg>\n");
+    Some(svg)
+}
+
+/* -------------------------------------------------------------------------- */
+
+const ROW_HEIGHT: f64 = 24.0;
+const LABEL_WIDTH: f64 = 60.0;
+const SPARK_WIDTH: f64 = 200.0;
+
+/// Total runtime of a day in every stored run that timed it, oldest first.
+fn day_history(timings: &Timings, day: Day) -> Vec<f64> {
+    timings
+        .history
+        .iter()
+        .filter_map(|run| run.data.iter().find(|t| t.day == day))
+        .map(|t| t.total_nanos)
+        .filter(|nanos| *nanos > 0.0)
+        .collect()
+}
+
+/// Sparklines of the runtime of every day over the stored runs. [`None`] if no day has been stored twice.
+pub fn history_chart(timings: &Timings) -> Option<String> {
+    let rows: Vec<(Day, Vec<f64>)> = timings
+        .data
+        .iter()
+        .map(|t| (t.day, day_history(timings, t.day)))
+        .filter(|(_, values)| values.len() > 1)
+        .collect();
+
+    if rows.is_empty() {
+        return None;
+    }
+
+    let width = LABEL_WIDTH + SPARK_WIDTH + 150.0;
+    #[allow(clippy::cast_precision_loss)]
+    let height = ROW_HEIGHT * rows.len() as f64 + 10.0;
+
+    let mut svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+    );
+    let _ = write!(svg, r#"<rect width="100%" height="100%" fill="white"/>"#);
+
+    for (row, (day, values)) in rows.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let top = 5.0 + ROW_HEIGHT * row as f64;
+        let (first, last) = (values[0], values[values.len() - 1]);
+        let color = if last > first {
+            SLOWER_COLOR
+        } else {
+            FASTER_COLOR
+        };
+
+        // every sparkline is scaled to its own range, the label gives the absolute figures.
+        let (min, max) = values.iter().fold((f64::MAX, f64::MIN), |(min, max), v| {
+            (min.min(*v), max.max(*v))
+        });
+        let range = if max > min { max - min } else { 1.0 };
+
+        #[allow(clippy::cast_precision_loss)]
+        let points: Vec<String> = values
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                let x = LABEL_WIDTH + SPARK_WIDTH * i as f64 / (values.len() - 1) as f64;
+                let y = top + 4.0 + (ROW_HEIGHT - 8.0) * (1.0 - (v - min) / range);
+                format!("{x:.1},{y:.1}")
+            })
+            .collect();
+
+        let _ = write!(
+            svg,
+            r#"<text x="4" y="{}" {FONT}>Day {day}</text><polyline points="{}" fill="none" stroke="{color}" stroke-width="1.5"/><text x="{}" y="{}" {FONT}>{} ({:+.1}%)</text>"#,
+            top + ROW_HEIGHT / 2.0 + 4.0,
+            points.join(" "),
+            LABEL_WIDTH + SPARK_WIDTH + 10.0,
+            top + ROW_HEIGHT / 2.0 + 4.0,
+            format_nanos(last),
+            (last - first) / first * 100.0
+        );
+    }
+
+    svg.push_str("</svg>\n");
+    Some(svg)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{decades, format_decade, history_chart, runtime_chart};
+    use crate::{
+        day,
+        template::{
+            history::Run,
+            report::Step,
+            timings::{Timing, Timings},
+            Day,
+        },
+    };
+
+    fn timing(day: Day, part_1: f64, part_2: f64) -> Timing {
+        Timing {
+            day,
+            parse: None,
+            part_1: None,
+            part_2: None,
+            total_nanos: part_1 + part_2,
+            status: None,
+            allocs: vec![],
+            nanos: vec![(Step::Part(1), part_1), (Step::Part(2), part_2)],
+        }
+    }
+
+    fn get_mock_timings() -> Timings {
+        Timings {
+            history: vec![],
+            data: vec![
+                timing(day!(1), 1_000.0, 10_000.0),
+                timing(day!(2), 1_000_000.0, 100.0),
+            ],
+        }
+    }
+
+    #[test]
+    fn computes_decades() {
+        assert_eq!(decades([150.0, 2_000.0].into_iter()), Some((2, 4)));
+        assert_eq!(decades([1_000.0].into_iter()), Some((3, 4)));
+        assert_eq!(decades([].into_iter()), None);
+    }
+
+    #[test]
+    fn formats_decades() {
+        assert_eq!(format_decade(0), "1ns");
+        assert_eq!(format_decade(2), "100ns");
+        assert_eq!(format_decade(3), "1µs");
+        assert_eq!(format_decade(7), "10ms");
+        assert_eq!(format_decade(10), "10s");
+    }
+
+    #[test]
+    fn draws_bars_on_log_scale() {
+        let svg = runtime_chart(&get_mock_timings()).unwrap();
+
+        assert_eq!(svg.starts_with("<svg"), true);
+        assert_eq!(svg.matches("<title>").count(), 4);
+        assert_eq!(svg.contains(">100ns<"), true);
+        assert_eq!(svg.contains(">1ms<"), true);
+        // 100ns is the bottom of the scale and 1ms its top, so 10µs sits exactly in the middle.
+        assert_eq!(svg.contains(r#"y="110.0" width="10" height="80.0""#), true);
+    }
+
+    #[test]
+    fn skips_runtime_chart_without_runtimes() {
+        let mut timings = get_mock_timings();
+        timings.data.iter_mut().for_each(|t| t.nanos.clear());
+        assert_eq!(runtime_chart(&timings), None);
+    }
+
+    #[test]
+    fn draws_sparklines_of_history() {
+        let mut timings = get_mock_timings();
+        assert_eq!(history_chart(&timings), None);
+
+        for (i, nanos) in [2_000.0, 1_500.0, 1_000.0].into_iter().enumerate() {
+            timings.record(Run {
+                timestamp: i as u64,
+                commit: None,
+                data: vec![timing(day!(1), nanos, 0.0)],
+            });
+        }
+
+        let svg = history_chart(&timings).unwrap();
+        assert_eq!(svg.matches("<polyline").count(), 1);
+        assert_eq!(svg.contains("Day 01"), true);
+        assert_eq!(svg.contains("(-50.0%)"), true);
+    }
+}
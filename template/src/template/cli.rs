@@ -0,0 +1,377 @@
+/// Command line interface of the template, run by the main binary of every year.
+use crate::template::commands::{all, download, read, refresh_tests, scaffold, solve, time};
+use args::{parse, AppArguments};
+
+#[cfg(feature = "today")]
+use crate::template::commands::today;
+
+mod args {
+    use crate::template::{
+        ab_bench::{AbConfig, DEFAULT_ROUNDS},
+        commands::{solve::MemoryTool, time::TimeOutputs},
+        examples::Selection,
+        export::{Export, ExportFormat},
+        history::{Baseline, CompareConfig, DEFAULT_THRESHOLD},
+        limits::Limits,
+        runner::{BenchConfig, InputSource},
+        Day, Year,
+    };
+    use std::process;
+    use std::time::Duration;
+
+    pub enum AppArguments {
+        Download {
+            day: Day,
+            examples: Selection,
+        },
+        Read {
+            day: Day,
+            examples: Selection,
+        },
+        RefreshTests {
+            day: Day,
+            examples: Selection,
+        },
+        Scaffold {
+            day: Day,
+            download: bool,
+            overwrite: bool,
+            examples: Selection,
+        },
+        Solve {
+            day: Day,
+            release: bool,
+            memory: MemoryTool,
+            submit: Option<u8>,
+            accept: Option<u8>,
+            input: InputSource,
+            part: Option<u8>,
+        },
+        SolveAllInputs {
+            day: Day,
+            release: bool,
+            part: Option<u8>,
+            limits: Limits,
+        },
+        All {
+            release: bool,
+            limits: Limits,
+            jobs: usize,
+        },
+        Time {
+            all: bool,
+            day: Option<Day>,
+            bench: BenchConfig,
+            limits: Limits,
+            jobs: usize,
+            outputs: TimeOutputs,
+        },
+        TimeAgainst {
+            day: Day,
+            config: AbConfig,
+            bench: BenchConfig,
+            limits: Limits,
+        },
+        #[cfg(feature = "today")]
+        Today {
+            wait: bool,
+        },
+    }
+
+    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
+        let mut args = pico_args::Arguments::from_env();
+
+        let app_args = match args.subcommand()?.as_deref() {
+            Some("all") => AppArguments::All {
+                release: args.contains("--release"),
+                limits: parse_limits(&mut args)?,
+                jobs: parse_jobs(&mut args)?,
+            },
+            Some("time") => {
+                let all = args.contains("--all");
+                let store = args.contains("--store");
+                let default = BenchConfig::default();
+                let bench = BenchConfig {
+                    samples: args.opt_value_from_str("--samples")?,
+                    budget: args
+                        .opt_value_from_str("--budget")?
+                        .map_or(default.budget, Duration::from_millis),
+                    warmup: args
+                        .opt_value_from_str("--warmup")?
+                        .map_or(default.warmup, Duration::from_millis),
+                    alloc: args.contains("--alloc"),
+                };
+
+                if let Some(rev) = args.opt_value_from_str("--against")? {
+                    return Ok(AppArguments::TimeAgainst {
+                        config: AbConfig {
+                            rev,
+                            rounds: args
+                                .opt_value_from_fn("--rounds", parse_rounds)?
+                                .unwrap_or(DEFAULT_ROUNDS),
+                            input: InputSource::parse(&mut args)?,
+                        },
+                        limits: parse_limits(&mut args)?,
+                        day: args.free_from_fn(parse_day)?,
+                        bench,
+                    });
+                }
+
+                let compare = parse_compare(&mut args)?;
+                let export_format: Option<ExportFormat> = args.opt_value_from_str("--export")?;
+                let limits = parse_limits(&mut args)?;
+                let jobs = parse_jobs(&mut args)?;
+
+                // NOTE: the path of `--export` follows the optional day as a free argument.
+                let (day, export) = match export_format {
+                    None => (args.opt_free_from_fn(parse_day)?, None),
+                    Some(format) => {
+                        let first: Option<String> = args.opt_free_from_str()?;
+                        let second: Option<String> = args.opt_free_from_str()?;
+                        let (day, path) = match (first, second) {
+                            (Some(day), Some(path)) => (Some(parse_day(&day)?), path),
+                            (Some(path), None) => (None, path),
+                            (None, _) => {
+                                return Err("`--export <format>` expects a path to write to.".into())
+                            }
+                        };
+                        (
+                            day,
+                            Some(Export {
+                                format,
+                                path: path.into(),
+                            }),
+                        )
+                    }
+                };
+
+                AppArguments::Time {
+                    all,
+                    day,
+                    bench,
+                    limits,
+                    jobs,
+                    outputs: TimeOutputs {
+                        store,
+                        compare,
+                        export,
+                    },
+                }
+            }
+            Some("download") => AppArguments::Download {
+                examples: parse_examples(&mut args)?,
+                day: args.free_from_fn(parse_day)?,
+            },
+            Some("read") => AppArguments::Read {
+                examples: parse_examples(&mut args)?,
+                day: args.free_from_fn(parse_day)?,
+            },
+            Some("refresh-tests") => AppArguments::RefreshTests {
+                examples: parse_examples(&mut args)?,
+                day: args.free_from_fn(parse_day)?,
+            },
+            Some("scaffold") => AppArguments::Scaffold {
+                examples: parse_examples(&mut args)?,
+                day: args.free_from_fn(parse_day)?,
+                download: args.contains("--download"),
+                overwrite: args.contains("--overwrite"),
+            },
+            Some("solve") => {
+                let day = args.free_from_fn(parse_day)?;
+                let submit = args.opt_value_from_str("--submit")?;
+                let accept = args.opt_value_from_str("--accept")?;
+                let input = InputSource::parse(&mut args)?;
+                let part = args.opt_value_from_fn("--part", parse_part)?;
+
+                if submit.is_some() && input != InputSource::Puzzle(None) {
+                    return Err("`--submit` can only be used with the default puzzle input.".into());
+                }
+                if accept.is_some() && !matches!(input, InputSource::Puzzle(_)) {
+                    return Err("`--accept` can only be used with a puzzle input.".into());
+                }
+
+                if args.contains("--all-inputs") {
+                    if input != InputSource::Puzzle(None) || submit.is_some() || accept.is_some() {
+                        return Err("`--all-inputs` cannot be combined with another input, `--submit` or `--accept`.".into());
+                    }
+
+                    AppArguments::SolveAllInputs {
+                        day,
+                        release: args.contains("--release"),
+                        part,
+                        limits: parse_limits(&mut args)?,
+                    }
+                } else {
+                    AppArguments::Solve {
+                        day,
+                        release: args.contains("--release"),
+                        submit,
+                        accept,
+                        memory: match (args.contains("--dhat"), args.contains("--alloc")) {
+                            (true, true) => {
+                                return Err("`--dhat` and `--alloc` cannot be combined.".into())
+                            }
+                            (true, false) => MemoryTool::Dhat,
+                            (false, true) => MemoryTool::Alloc,
+                            (false, false) => MemoryTool::Off,
+                        },
+                        input,
+                        part,
+                    }
+                }
+            }
+            #[cfg(feature = "today")]
+            Some("today") => AppArguments::Today {
+                wait: args.contains("--wait"),
+            },
+            Some(x) => {
+                eprintln!("Unknown command: {x}");
+                process::exit(1);
+            }
+            None => {
+                eprintln!("No command specified.");
+                process::exit(1);
+            }
+        };
+
+        let remaining = args.finish();
+        if !remaining.is_empty() {
+            eprintln!("Warning: unknown argument(s): {remaining:?}.");
+        }
+
+        Ok(app_args)
+    }
+
+    /// Parses a day, which must be part of the calendar of the current year (see [`Year::from_env`]).
+    fn parse_day(s: &str) -> Result<Day, String> {
+        let day: Day = s.parse().map_err(|e| format!("{e}, got `{s}`"))?;
+        match Year::from_env() {
+            Some(year) if !year.contains(day) => Err(format!(
+                "day {day} is not part of {year}, which has {} days",
+                year.day_count()
+            )),
+            _ => Ok(day),
+        }
+    }
+
+    fn parse_part(s: &str) -> Result<u8, String> {
+        match s.parse() {
+            Ok(part @ (1 | 2)) => Ok(part),
+            _ => Err(format!("expected part 1 or 2, got `{s}`")),
+        }
+    }
+
+    /// Parses `--compare`, `--baseline <rev>` and `--threshold <percent>`. Either of the options implies `--compare`.
+    fn parse_compare(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<CompareConfig>, pico_args::Error> {
+        let compare = args.contains("--compare");
+        let baseline: Option<String> = args.opt_value_from_str("--baseline")?;
+        let threshold = args.opt_value_from_fn("--threshold", |s| match s.parse::<f64>() {
+            Ok(threshold) if threshold >= 0.0 => Ok(threshold),
+            _ => Err(format!("expected a positive percentage, got `{s}`")),
+        })?;
+
+        if !compare && baseline.is_none() && threshold.is_none() {
+            return Ok(None);
+        }
+
+        Ok(Some(CompareConfig {
+            baseline: baseline.map_or(Baseline::Previous, Baseline::Commit),
+            threshold: threshold.unwrap_or(DEFAULT_THRESHOLD),
+        }))
+    }
+
+    fn parse_rounds(s: &str) -> Result<u32, String> {
+        match s.parse() {
+            Ok(rounds) if rounds >= 2 => Ok(rounds),
+            _ => Err(format!("expected at least 2 rounds, got `{s}`")),
+        }
+    }
+
+    /// Parses `--jobs <n>`, the number of days to run at the same time. Days run one after another by default.
+    fn parse_jobs(args: &mut pico_args::Arguments) -> Result<usize, pico_args::Error> {
+        let jobs = args.opt_value_from_fn("--jobs", |s| match s.parse() {
+            Ok(0) | Err(_) => Err(format!("expected a positive number of jobs, got `{s}`")),
+            Ok(jobs) => Ok(jobs),
+        })?;
+        Ok(jobs.unwrap_or(1))
+    }
+
+    /// Parses `--example-block <n>` and `--example-2-block <n>`, the code blocks to use as examples of part one and two.
+    fn parse_examples(args: &mut pico_args::Arguments) -> Result<Selection, pico_args::Error> {
+        Ok(Selection {
+            part_one: args.opt_value_from_str("--example-block")?,
+            part_two: args.opt_value_from_str("--example-2-block")?,
+        })
+    }
+
+    fn parse_limits(args: &mut pico_args::Arguments) -> Result<Limits, pico_args::Error> {
+        Ok(Limits::from_args(
+            args.opt_value_from_str("--timeout")?,
+            args.opt_value_from_str("--memory-limit")?,
+        ))
+    }
+}
+
+pub fn main() {
+    match parse() {
+        Err(err) => {
+            eprintln!("Error: {err}");
+            std::process::exit(1);
+        }
+        Ok(args) => match args {
+            AppArguments::All {
+                release,
+                limits,
+                jobs,
+            } => all::handle(release, &limits, jobs),
+            AppArguments::Time {
+                day,
+                all,
+                bench,
+                limits,
+                jobs,
+                outputs,
+            } => time::handle(day, all, &bench, &limits, jobs, &outputs),
+            AppArguments::TimeAgainst {
+                day,
+                config,
+                bench,
+                limits,
+            } => time::handle_against(day, &config, &bench, &limits),
+            AppArguments::Download { day, examples } => download::handle(day, &examples),
+            AppArguments::Read { day, examples } => read::handle(day, &examples),
+            AppArguments::RefreshTests { day, examples } => refresh_tests::handle(day, &examples),
+            AppArguments::Scaffold {
+                day,
+                download,
+                overwrite,
+                examples,
+            } => {
+                scaffold::handle(day, overwrite);
+                if download {
+                    download::handle(day, &examples);
+                    scaffold::fill_tests(day);
+                }
+            }
+            AppArguments::Solve {
+                day,
+                release,
+                memory,
+                submit,
+                accept,
+                input,
+                part,
+            } => solve::handle(day, release, memory, submit, accept, &input, part),
+            AppArguments::SolveAllInputs {
+                day,
+                release,
+                part,
+                limits,
+            } => solve::handle_all_inputs(day, release, part, &limits),
+            #[cfg(feature = "today")]
+            AppArguments::Today { wait } => today::handle(wait),
+        },
+    };
+}
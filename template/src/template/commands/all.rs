@@ -0,0 +1,32 @@
+use std::process;
+
+use crate::template::{
+    all_days, limits::Limits, report::Step, run_multi::run_multi, ANSI_BOLD, ANSI_RESET,
+};
+
+pub fn handle(is_release: bool, limits: &Limits, jobs: usize) {
+    let run = run_multi(&all_days().collect(), is_release, None, limits, jobs);
+
+    println!();
+    println!(
+        "{ANSI_BOLD}Verified:{ANSI_RESET} {} passed, {} failed, {} without known answer.",
+        run.passed,
+        run.failed.len(),
+        run.unverified
+    );
+
+    for (target, step, message) in &run.errors {
+        let step = match step {
+            Step::Parse => "parse".to_string(),
+            Step::Part(part) => format!("part {part}"),
+        };
+        eprintln!("✖ {target}, {step}: {message}");
+    }
+
+    if !run.failed.is_empty() {
+        for (target, part) in &run.failed {
+            eprintln!("✗ {target}, part {part} does not match its known answer.");
+        }
+        process::exit(1);
+    }
+}
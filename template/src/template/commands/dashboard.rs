@@ -0,0 +1,24 @@
+use std::{path::Path, process};
+
+use crate::template::dashboard;
+
+/// Updates the dashboard in the readme at the root of the repository, which contains the template crate.
+pub fn handle() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+
+    match dashboard::update(root) {
+        Ok(summaries) => {
+            for summary in summaries {
+                println!(
+                    "{}: {} stars, {} solved days",
+                    summary.year, summary.stars, summary.solved_days
+                );
+            }
+            println!("Stored updated dashboard.");
+        }
+        Err(_) => {
+            eprintln!("Failed to store updated dashboard.");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use crate::template::{aoc_client, examples::Selection, Day};
+use std::process;
+
+pub fn handle(day: Day, examples: &Selection) {
+    if let Err(e) = aoc_client::download(day, examples) {
+        eprintln!("failed to download puzzle: {e}");
+        process::exit(1);
+    };
+}
@@ -1,6 +1,10 @@
 pub mod all;
+pub mod dashboard;
 pub mod download;
 pub mod read;
+pub mod refresh_tests;
 pub mod scaffold;
 pub mod solve;
 pub mod time;
+#[cfg(feature = "today")]
+pub mod today;
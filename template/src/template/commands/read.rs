@@ -0,0 +1,10 @@
+use std::process;
+
+use crate::template::{aoc_client, examples::Selection, Day};
+
+pub fn handle(day: Day, examples: &Selection) {
+    if let Err(e) = aoc_client::read(day, examples) {
+        eprintln!("failed to read puzzle: {e}");
+        process::exit(1);
+    };
+}
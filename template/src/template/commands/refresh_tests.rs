@@ -0,0 +1,12 @@
+use crate::template::{aoc_client, commands::scaffold, examples::Selection, Day};
+
+pub fn handle(day: Day, examples: &Selection) {
+    // part two is only revealed once part one is solved, so the stored description may be outdated.
+    if let Err(e) = aoc_client::update_puzzle(day, examples) {
+        eprintln!("failed to update puzzle, using the stored description: {e}");
+    }
+
+    if !scaffold::fill_tests(day) {
+        println!("🎄 Tests of \"src/bin/{day}.rs\" are up to date.");
+    }
+}
@@ -0,0 +1,244 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::Write,
+    process,
+};
+
+use crate::template::{examples, Day, Year};
+
+const MODULE_TEMPLATE: &str =
+    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+
+fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
+    let mut file = OpenOptions::new();
+    if overwrite {
+        file.create(true);
+    } else {
+        file.create_new(true);
+    }
+    file.truncate(true).write(true).open(path)
+}
+
+fn create_file(path: &str) -> Result<File, std::io::Error> {
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)
+}
+
+pub fn handle(day: Day, overwrite: bool) {
+    // the `day!` macro of the module would not compile for a day outside of the calendar.
+    if let Some(year) = Year::from_env().filter(|year| !year.contains(day)) {
+        eprintln!(
+            "Day {day} is not part of {year}, which has {} days.",
+            year.day_count()
+        );
+        process::exit(1);
+    }
+
+    let input_path = format!("data/inputs/{day}.txt");
+    let example_path = format!("data/examples/{day}.txt");
+    let module_path = format!("src/bin/{day}.rs");
+
+    let mut file = match safe_create_file(&module_path, overwrite) {
+        Ok(file) => file,
+        Err(e) => {
+            eprintln!("Failed to create module file: {e}");
+            process::exit(1);
+        }
+    };
+
+    match file.write_all(
+        MODULE_TEMPLATE
+            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
+            .as_bytes(),
+    ) {
+        Ok(()) => {
+            println!("Created module file \"{}\"", &module_path);
+        }
+        Err(e) => {
+            eprintln!("Failed to write module contents: {e}");
+            process::exit(1);
+        }
+    }
+
+    match create_file(&input_path) {
+        Ok(_) => {
+            println!("Created empty input file \"{}\"", &input_path);
+        }
+        Err(e) => {
+            eprintln!("Failed to create input file: {e}");
+            process::exit(1);
+        }
+    }
+
+    match create_file(&example_path) {
+        Ok(_) => {
+            println!("Created empty example file \"{}\"", &example_path);
+        }
+        Err(e) => {
+            eprintln!("Failed to create example file: {e}");
+            process::exit(1);
+        }
+    }
+
+    fill_tests(day);
+
+    println!("---");
+    println!("🎄 Type `cargo solve {day}` to run your solution.");
+}
+
+/// Fills in the generated tests of a day with the example answers of `data/puzzles/DD.md` and its example files.
+/// Returns whether the module changed.
+pub fn fill_tests(day: Day) -> bool {
+    let module_path = format!("src/bin/{day}.rs");
+    let (Ok(puzzle), Ok(source)) = (
+        fs::read_to_string(format!("data/puzzles/{day}.md")),
+        fs::read_to_string(&module_path),
+    ) else {
+        return false;
+    };
+
+    let updated = update_tests(
+        &source,
+        &examples::answers(&puzzle),
+        &examples::part_files(day),
+    );
+    if updated == source {
+        return false;
+    }
+
+    match fs::write(&module_path, updated) {
+        Ok(()) => {
+            println!("Filled in example tests of \"{}\"", &module_path);
+            true
+        }
+        Err(e) => {
+            eprintln!("Failed to write module contents: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Updates the tests generated from `template.txt`, leaving tests that were changed by hand alone:
+///  1. `assert_eq!(result, None)` in `test_part_one` / `test_part_two` asserts the known example answer.
+///  2. a part with an example file of its own reads it with `read_file_part` instead of `read_file`.
+///  3. every further example file `DD-N.txt` gets a test `test_example_N`. The puzzle does not tell which part such a
+///     file belongs to, so the test asserts `None` for both parts.
+fn update_tests(source: &str, answers: &[Option<String>; 2], part_files: &[u8]) -> String {
+    let mut lines: Vec<String> = source.lines().map(String::from).collect();
+
+    for (part, name) in [(1, "one"), (2, "two")] {
+        let Some(start) = lines.iter().position(|l| {
+            l.trim_start()
+                .starts_with(&format!("fn test_part_{name}()"))
+        }) else {
+            continue;
+        };
+
+        let indent = lines[start].len() - lines[start].trim_start().len();
+        let close = format!("{}}}", " ".repeat(indent));
+        let end = lines[start..]
+            .iter()
+            .position(|l| *l == close)
+            .map_or(lines.len(), |i| start + i);
+
+        for line in &mut lines[start..end] {
+            if part_files.contains(&part) {
+                *line = line.replace(
+                    "read_file(\"examples\", DAY)",
+                    &format!("read_file_part(\"examples\", DAY, {part})"),
+                );
+            }
+            if let Some(answer) = &answers[usize::from(part) - 1] {
+                if line.trim() == "assert_eq!(result, None);" {
+                    *line = line.replace("None", &format!("Some({answer})"));
+                }
+            }
+        }
+    }
+
+    // further tests go at the end of the `tests` module, which closes the file.
+    for n in part_files.iter().filter(|n| **n > 2) {
+        if lines
+            .iter()
+            .any(|l| l.contains(&format!("fn test_example_{n}()")))
+        {
+            continue;
+        }
+        let Some(position) = lines.iter().rposition(|l| l == "}") else {
+            break;
+        };
+
+        let test = format!(
+            "
+    #[test]
+    fn test_example_{n}() {{
+        let input = advent_of_code::template::read_file_part(\"examples\", DAY, {n});
+        assert_eq!(part_one(&input), None);
+        assert_eq!(part_two(&input), None);
+    }}"
+        );
+        lines.splice(position..position, test.lines().map(String::from));
+    }
+
+    let mut updated = lines.join("\n");
+    if source.ends_with('\n') {
+        updated.push('\n');
+    }
+    updated
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{update_tests, MODULE_TEMPLATE};
+
+    #[test]
+    fn fills_in_template_tests() {
+        let source = MODULE_TEMPLATE.replace("%DAY_NUMBER%", "1");
+        let updated = update_tests(&source, &[Some("11".into()), Some("31".into())], &[2, 3]);
+
+        assert_eq!(updated.contains("assert_eq!(result, Some(11));"), true);
+        assert_eq!(updated.contains("assert_eq!(result, Some(31));"), true);
+        assert_eq!(
+            updated.contains("part_one(&advent_of_code::template::read_file(\"examples\", DAY))"),
+            true
+        );
+        assert_eq!(
+            updated.contains(
+                "part_two(&advent_of_code::template::read_file_part(\"examples\", DAY, 2))"
+            ),
+            true
+        );
+        assert_eq!(updated.contains("fn test_example_3()"), true);
+        assert_eq!(updated.ends_with("    }\n}\n"), true);
+
+        // running it again changes nothing, and answers edited by hand are kept.
+        assert_eq!(
+            update_tests(&updated, &[Some("12".into()), None], &[2, 3]),
+            updated
+        );
+    }
+
+    #[test]
+    fn tests_further_examples_with_both_parts() {
+        let source = MODULE_TEMPLATE.replace("%DAY_NUMBER%", "1");
+        let updated = update_tests(&source, &[Some("11".into()), None], &[3]);
+
+        // `01-3.txt` may hold another example of part one, so its test does not assume part two.
+        let test = &updated[updated.find("fn test_example_3()").unwrap()..];
+        assert_eq!(test.contains("read_file_part(\"examples\", DAY, 3)"), true);
+        assert_eq!(test.contains("assert_eq!(part_one(&input), None);"), true);
+        assert_eq!(test.contains("assert_eq!(part_two(&input), None);"), true);
+        assert_eq!(updated.contains("assert_eq!(result, Some(11));"), true);
+    }
+
+    #[test]
+    fn keeps_unknown_answers() {
+        let source = MODULE_TEMPLATE.replace("%DAY_NUMBER%", "1");
+        assert_eq!(update_tests(&source, &[None, None], &[]), source);
+    }
+}
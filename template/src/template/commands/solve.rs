@@ -0,0 +1,173 @@
+use std::process::{self, Command, Stdio};
+use std::time::Duration;
+
+use crate::template::{
+    limits::Limits,
+    report::{Record, Status, Step},
+    run_multi::run_inputs,
+    runner::InputSource,
+    Day,
+};
+
+/// Memory tooling for a solve run.
+pub enum MemoryTool {
+    Off,
+    /// Profile the heap with dhat, see `--dhat`.
+    Dhat,
+    /// Count the allocations of each part, see `--alloc`.
+    Alloc,
+}
+
+pub fn handle(
+    day: Day,
+    release: bool,
+    memory: MemoryTool,
+    submit_part: Option<u8>,
+    accept_part: Option<u8>,
+    input: &InputSource,
+    part: Option<u8>,
+) {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+
+    match memory {
+        MemoryTool::Dhat => cmd_args.extend([
+            "--profile".to_string(),
+            "dhat".to_string(),
+            "--features".to_string(),
+            "dhat-heap".to_string(),
+        ]),
+        MemoryTool::Alloc => {
+            if release {
+                cmd_args.push("--release".to_string());
+            }
+            cmd_args.extend(["--features".to_string(), "count-allocs".to_string()]);
+        }
+        MemoryTool::Off => {
+            if release {
+                cmd_args.push("--release".to_string());
+            }
+        }
+    }
+
+    cmd_args.push("--".to_string());
+
+    if let MemoryTool::Alloc = memory {
+        cmd_args.push("--alloc".to_string());
+    }
+
+    if let Some(submit_part) = submit_part {
+        cmd_args.push("--submit".to_string());
+        cmd_args.push(submit_part.to_string());
+    }
+
+    if let Some(accept_part) = accept_part {
+        cmd_args.push("--accept".to_string());
+        cmd_args.push(accept_part.to_string());
+    }
+
+    cmd_args.extend(input.to_args());
+
+    if let Some(part) = part {
+        cmd_args.push("--part".to_string());
+        cmd_args.push(part.to_string());
+    }
+
+    let mut cmd = Command::new("cargo")
+        .args(&cmd_args)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .unwrap();
+
+    cmd.wait().unwrap();
+}
+
+/// Runs a day once per puzzle input and prints a table of the answers and timings for each input.
+pub fn handle_all_inputs(day: Day, release: bool, part: Option<u8>, limits: &Limits) {
+    let Some(results) = run_inputs(day, release, part, limits) else {
+        println!("Not solved.");
+        return;
+    };
+
+    if results.is_empty() {
+        eprintln!("No puzzle inputs found for day {day}.");
+        process::exit(1);
+    }
+
+    let parts: Vec<u8> = part.map_or_else(|| vec![1, 2], |part| vec![part]);
+
+    let header: Vec<String> = ["Input".to_string()]
+        .into_iter()
+        .chain(parts.iter().map(|part| format!("Part {part}")))
+        .collect();
+
+    let rows: Vec<Vec<String>> = results
+        .iter()
+        .map(|(input, records)| {
+            let name = input.as_deref().unwrap_or("default").to_string();
+            [name]
+                .into_iter()
+                .chain(parts.iter().map(|part| {
+                    records
+                        .iter()
+                        .find(|r| r.step == Step::Part(*part))
+                        .map_or_else(|| "-".into(), format_cell)
+                }))
+                .collect()
+        })
+        .collect();
+
+    let widths: Vec<usize> = (0..header.len())
+        .map(|i| {
+            rows.iter()
+                .chain([&header])
+                .map(|row| row[i].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let print_row = |row: &[String]| {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        println!("{}", cells.join(" | ").trim_end());
+    };
+
+    print_row(&header);
+    for row in &rows {
+        print_row(row);
+    }
+
+    let failed = results
+        .iter()
+        .flat_map(|(_, records)| records)
+        .any(|r| r.verified == Some(false));
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn format_cell(record: &Record) -> String {
+    match (&record.status, &record.answer) {
+        (Status::Ok, Some(answer)) => {
+            let answer = if answer.contains('\n') { "▼" } else { answer };
+
+            #[allow(clippy::cast_possible_truncation)]
+            let duration = Duration::from_nanos(record.duration_nanos as u64);
+
+            let verdict = match record.verified {
+                Some(true) => " ✓",
+                Some(false) => " ✗",
+                None => "",
+            };
+
+            format!("{answer} ({duration:.1?}){verdict}")
+        }
+        (Status::Ok | Status::NoResult, _) => "✖".into(),
+        (status, _) => format!("✖ {}", status.describe()),
+    }
+}
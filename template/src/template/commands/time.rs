@@ -0,0 +1,109 @@
+use std::{collections::HashSet, process};
+
+use crate::template::ab_bench::{self, AbConfig};
+use crate::template::export::Export;
+use crate::template::history::{self, CompareConfig, Run};
+use crate::template::limits::Limits;
+use crate::template::run_multi::run_multi;
+use crate::template::runner::BenchConfig;
+use crate::template::timings::Timings;
+use crate::template::{all_days, readme_benchmarks, Day};
+
+/// What `cargo time` does with the timings once the days ran.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TimeOutputs {
+    /// Store the timings in `timings.json` and the readme, set with `--store`.
+    pub store: bool,
+    /// Compare the timings against stored runs, set with `--compare`.
+    pub compare: Option<CompareConfig>,
+    /// Write the timings to a file, set with `--export <format> <path>`.
+    pub export: Option<Export>,
+}
+
+pub fn handle(
+    day: Option<Day>,
+    run_all: bool,
+    bench: &BenchConfig,
+    limits: &Limits,
+    jobs: usize,
+    outputs: &TimeOutputs,
+) {
+    let compare = match outputs.compare.as_ref().map(resolve_compare).transpose() {
+        Ok(compare) => compare,
+        Err(e) => {
+            eprintln!("Failed to compare timings: {e}");
+            process::exit(1);
+        }
+    };
+
+    let stored_timings = Timings::read_from_file();
+
+    let days_to_run = day.map_or_else(
+        || {
+            // a comparison needs a new run of every day, not only of the days missing a timing.
+            if run_all || compare.is_some() {
+                all_days().collect()
+            } else {
+                // when the `--all` flag is not set, filter out days that are fully benched.
+                all_days()
+                    .filter(|day| !stored_timings.is_day_complete(*day))
+                    .collect()
+            }
+        },
+        |day| HashSet::from([day]),
+    );
+
+    let timings = run_multi(&days_to_run, true, Some(bench), limits, jobs)
+        .timings
+        .unwrap();
+
+    // NOTE: compare before storing, so that the new run is not its own reference.
+    let regressed = compare.as_ref().is_some_and(|compare| {
+        let deltas = history::compare(&stored_timings.history, &timings.data, &compare.baseline);
+        history::print_deltas(&deltas, compare.threshold)
+    });
+
+    let mut merged_timings = stored_timings.merge(&timings);
+
+    if let Some(export) = &outputs.export {
+        match export.write(&merged_timings) {
+            Ok(()) => println!("\nExported timings to \"{}\".", export.path.display()),
+            Err(e) => eprintln!("\nFailed to export timings: {e}"),
+        }
+    }
+
+    if outputs.store {
+        merged_timings.record(Run::new(timings.data));
+        merged_timings.store_file().unwrap();
+
+        println!();
+        match readme_benchmarks::update(merged_timings) {
+            Ok(()) => {
+                println!("Stored updated benchmarks.");
+            }
+            Err(_) => {
+                eprintln!("Failed to store updated benchmarks.");
+            }
+        }
+    }
+
+    if regressed {
+        eprintln!("At least one step regressed by more than the threshold.");
+        process::exit(1);
+    }
+}
+
+fn resolve_compare(compare: &CompareConfig) -> Result<CompareConfig, String> {
+    Ok(CompareConfig {
+        baseline: history::resolve_baseline(&compare.baseline)?,
+        threshold: compare.threshold,
+    })
+}
+
+/// Benches a day of the working tree against another revision, see [`ab_bench`].
+pub fn handle_against(day: Day, config: &AbConfig, bench: &BenchConfig, limits: &Limits) {
+    if let Err(e) = ab_bench::run(day, config, bench, limits) {
+        eprintln!("Failed to compare against {}: {e}", config.rev);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,147 @@
+/// `cargo today`: scaffolds, downloads and reads the puzzle of the day.
+///
+/// With `--wait`, the next puzzle of the year is scaffolded ahead of time and downloaded as soon as it unlocks.
+use std::{
+    io::{self, Write},
+    path::Path,
+    process, thread,
+    time::Duration,
+};
+
+use crate::template::{
+    aoc_client,
+    commands::{download, read, scaffold},
+    examples::Selection,
+    server_time, Day, PuzzleId, Year,
+};
+
+/// Time between two attempts to download a puzzle which has not unlocked yet.
+const RETRY_INTERVAL: Duration = Duration::from_secs(5);
+/// Attempts to download a puzzle after its unlock time, which covers a few minutes of clock skew.
+const MAX_ATTEMPTS: u32 = 60;
+
+pub fn handle(wait: bool) {
+    let Some(year) = Year::from_env() else {
+        eprintln!("`AOC_YEAR` is not set.");
+        process::exit(1);
+    };
+
+    if wait {
+        let Some(id) = PuzzleId::next_unlock(year, server_time()) else {
+            eprintln!(
+                "Every puzzle of {year} has already unlocked. Please use `cargo today` instead."
+            );
+            process::exit(1);
+        };
+
+        scaffold_once(id.day);
+        count_down(id);
+        download_when_unlocked(id.day);
+        finish(id.day);
+        return;
+    }
+
+    match PuzzleId::today().filter(|id| id.year == year) {
+        Some(id) => {
+            scaffold_once(id.day);
+            download::handle(id.day, &Selection::default());
+            finish(id.day);
+        }
+        None => {
+            eprintln!(
+                "`today` command can only be run during the advent of the year set in `AOC_YEAR`, \
+                until the last day of its calendar. Please use `scaffold` with a specific day, \
+                or `cargo today --wait` to wait for the next puzzle."
+            );
+            process::exit(1)
+        }
+    }
+}
+
+/// Scaffolds a day unless its module exists already, e.g. from an earlier `cargo today --wait`.
+fn scaffold_once(day: Day) {
+    if Path::new(&format!("src/bin/{day}.rs")).exists() {
+        println!("Module \"src/bin/{day}.rs\" exists already, skipping scaffold.");
+    } else {
+        scaffold::handle(day, false);
+    }
+}
+
+/// Prints the time left until the puzzle unlocks, once per second.
+fn count_down(id: PuzzleId) {
+    let unlock = id.unlock_time();
+    while let Ok(remaining) = (unlock - server_time()).to_std() {
+        if remaining.is_zero() {
+            break;
+        }
+        print!(
+            "\r⏳ Day {} unlocks in {} ",
+            id.day,
+            format_remaining(remaining)
+        );
+        let _ = io::stdout().flush();
+        thread::sleep(remaining.min(Duration::from_secs(1)));
+    }
+    println!("\r🔓 Day {} of {} is unlocked.{:10}", id.day, id.year, "");
+}
+
+fn format_remaining(remaining: Duration) -> String {
+    // round up, so that the countdown reaches zero when the puzzle unlocks.
+    let seconds = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+    let time = format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600 % 24,
+        seconds / 60 % 60,
+        seconds % 60
+    );
+
+    match seconds / 86400 {
+        0 => time,
+        days => format!("{days}d {time}"),
+    }
+}
+
+fn download_when_unlocked(day: Day) {
+    let mut attempt = 1;
+    loop {
+        match aoc_client::download(day, &Selection::default()) {
+            Ok(()) => return,
+            Err(e) if e.is_locked() && attempt < MAX_ATTEMPTS => {
+                eprintln!(
+                    "Day {day} is not unlocked yet, retrying in {}s.",
+                    RETRY_INTERVAL.as_secs()
+                );
+                attempt += 1;
+                thread::sleep(RETRY_INTERVAL);
+            }
+            Err(e) => {
+                eprintln!("failed to download puzzle: {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
+
+fn finish(day: Day) {
+    scaffold::fill_tests(day);
+    read::handle(day, &Selection::default());
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::format_remaining;
+    use std::time::Duration;
+
+    #[test]
+    fn formats_remaining_time() {
+        assert_eq!(format_remaining(Duration::from_secs(0)), "00:00:00");
+        assert_eq!(format_remaining(Duration::from_millis(500)), "00:00:01");
+        assert_eq!(format_remaining(Duration::from_secs(3599)), "00:59:59");
+        assert_eq!(
+            format_remaining(Duration::from_secs(26 * 3600 + 61)),
+            "1d 02:01:01"
+        );
+    }
+}
@@ -0,0 +1,181 @@
+/// Cross-year dashboard: summarizes the known answers and stored timings of every year in a table in the readme at
+/// the root of the repository, see `cargo dashboard`.
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::template::{
+    answers::{self, Answers},
+    readme_benchmarks::{locate_table, Error},
+    timings::Timings,
+};
+
+static MARKER: &str = "<!--- dashboard table --->";
+
+/// Progress of a single year.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Summary {
+    pub year: u16,
+    /// Known answers of the default inputs, one per part.
+    pub stars: usize,
+    /// Days with a known answer for both parts.
+    pub solved_days: usize,
+    /// Total of the stored timings, `None` if the year has not been benched.
+    pub total_millis: Option<f64>,
+}
+
+/// Directories of the years in a repository, e.g. `2023`, in order.
+fn years(root: &Path) -> Vec<(u16, PathBuf)> {
+    let Ok(entries) = fs::read_dir(root) else {
+        return vec![];
+    };
+
+    let mut years: Vec<(u16, PathBuf)> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = path.file_name()?.to_str()?;
+            let year = name.parse().ok().filter(|_| name.len() == 4)?;
+            path.is_dir().then_some((year, path))
+        })
+        .collect();
+
+    years.sort_unstable();
+    years
+}
+
+fn summarize(year: u16, dir: &Path) -> Summary {
+    let data_dir = dir.join("data");
+    let answers = answers::read_all(&data_dir);
+    let timings = Timings::read_from_path(&data_dir.join("timings.json"));
+
+    let stars_of = |answers: &Answers| [1, 2].iter().filter(|p| answers.get(**p).is_some()).count();
+
+    Summary {
+        year,
+        stars: answers.iter().map(|(_, a)| stars_of(a)).sum(),
+        solved_days: answers.iter().filter(|(_, a)| stars_of(a) == 2).count(),
+        total_millis: (!timings.data.is_empty()).then(|| timings.total_millis()),
+    }
+}
+
+fn construct_table(summaries: &[Summary]) -> String {
+    let mut lines: Vec<String> = vec![
+        MARKER.into(),
+        "| Year | Stars | Solved days | Total runtime |".into(),
+        "| :---: | :---: | :---: | :---: |".into(),
+    ];
+
+    for summary in summaries {
+        let runtime = summary
+            .total_millis
+            .map_or_else(|| "-".into(), |millis| format!("`{millis:.2}ms`"));
+        lines.push(format!(
+            "| [{year}](./{year}) | {} ⭐ | {} | {runtime} |",
+            summary.stars,
+            summary.solved_days,
+            year = summary.year,
+        ));
+    }
+
+    let stars: usize = summaries.iter().map(|s| s.stars).sum();
+    lines.push(String::new());
+    lines.push(format!("**Total: {stars} ⭐**"));
+    lines.push(MARKER.into());
+
+    lines.join("\n")
+}
+
+fn update_content(s: &mut String, summaries: &[Summary]) -> Result<(), Error> {
+    let positions = locate_table(s, MARKER)?;
+    s.replace_range(
+        positions.pos_start..positions.pos_end,
+        &construct_table(summaries),
+    );
+    Ok(())
+}
+
+/// Summarizes every year of the repository at `root` and updates the table in its readme.
+pub fn update(root: &Path) -> Result<Vec<Summary>, Error> {
+    let summaries: Vec<Summary> = years(root)
+        .iter()
+        .map(|(year, dir)| summarize(*year, dir))
+        .collect();
+
+    let path = root.join("README.md");
+    let mut readme = String::from_utf8_lossy(&fs::read(&path)?).to_string();
+    update_content(&mut readme, &summaries)?;
+    fs::write(&path, &readme)?;
+    Ok(summaries)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{summarize, update_content, years, Summary, MARKER};
+    use std::{env, fs, process};
+
+    fn summary(year: u16, stars: usize, total_millis: Option<f64>) -> Summary {
+        Summary {
+            year,
+            stars,
+            solved_days: stars / 2,
+            total_millis,
+        }
+    }
+
+    #[test]
+    fn summarizes_years() {
+        let root = env::temp_dir().join(format!("aoc-dashboard-{}", process::id()));
+        let data = root.join("2024").join("data");
+        fs::create_dir_all(data.join("answers")).unwrap();
+        fs::create_dir_all(root.join("2025")).unwrap();
+        fs::create_dir_all(root.join("template")).unwrap();
+
+        fs::write(
+            data.join("answers").join("01.json"),
+            r#"{ "part_1": "11", "part_2": "31" }"#,
+        )
+        .unwrap();
+        fs::write(
+            data.join("answers").join("02.json"),
+            r#"{ "part_1": "2", "part_2": null }"#,
+        )
+        .unwrap();
+        fs::write(
+            data.join("timings.json"),
+            r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": "2ms", "total_nanos": 3000000 }] }"#,
+        )
+        .unwrap();
+
+        let years = years(&root);
+        assert_eq!(
+            years.iter().map(|(year, _)| *year).collect::<Vec<_>>(),
+            vec![2024, 2025]
+        );
+
+        assert_eq!(summarize(2024, &years[0].1), summary(2024, 3, Some(3.0)));
+        assert_eq!(summarize(2025, &years[1].1), summary(2025, 0, None));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn updates_dashboard_table() {
+        let mut s = format!("# readme\n{MARKER}{MARKER}\nfoo");
+        let summaries = [summary(2023, 22, Some(12.345)), summary(2025, 0, None)];
+
+        update_content(&mut s, &summaries).unwrap();
+        update_content(&mut s, &summaries).unwrap();
+
+        assert_eq!(s.matches(MARKER).count(), 2);
+        assert_eq!(
+            s.contains("| [2023](./2023) | 22 ⭐ | 11 | `12.35ms` |"),
+            true
+        );
+        assert_eq!(s.contains("| [2025](./2025) | 0 ⭐ | 0 | - |"), true);
+        assert_eq!(s.contains("**Total: 22 ⭐**"), true);
+        assert_eq!(s.ends_with("\nfoo"), true);
+    }
+}
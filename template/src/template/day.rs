@@ -0,0 +1,464 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[cfg(feature = "today")]
+use chrono::{DateTime, Datelike, FixedOffset, TimeZone, Utc};
+
+#[cfg(feature = "today")]
+const SERVER_UTC_OFFSET: i32 = -5;
+
+/// Number of days in the calendars up to 2024.
+const LONG_CALENDAR: u8 = 25;
+/// Number of days in the calendars from 2025 on.
+const SHORT_CALENDAR: u8 = 12;
+/// First year with a [`SHORT_CALENDAR`].
+const FIRST_SHORT_YEAR: u16 = 2025;
+/// First year of Advent of Code.
+const FIRST_YEAR: u16 = 2015;
+
+/// A year of Advent of Code, which determines how many days the calendar has.
+///
+/// ```
+/// # use advent_of_code::Year;
+/// assert_eq!(Year::new(2024).unwrap().day_count(), 25);
+/// assert_eq!(Year::new(2025).unwrap().day_count(), 12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] if an event took place (or will take place) that year, returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        (year >= FIRST_YEAR).then_some(Self(year))
+    }
+
+    /// Reads the year of the current project from `AOC_YEAR`.
+    pub fn from_env() -> Option<Self> {
+        std::env::var("AOC_YEAR").ok()?.parse().ok()
+    }
+
+    /// Number of days in the calendar of this year.
+    pub const fn day_count(self) -> u8 {
+        if self.0 >= FIRST_SHORT_YEAR {
+            SHORT_CALENDAR
+        } else {
+            LONG_CALENDAR
+        }
+    }
+
+    /// Returns whether `day` is part of the calendar of this year.
+    pub fn contains(self, day: Day) -> bool {
+        day.0 <= self.day_count()
+    }
+
+    /// An iterator that yields every day of the calendar of this year.
+    pub fn days(self) -> AllDays {
+        AllDays {
+            current: 1,
+            last: self.day_count(),
+        }
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    // Not part of the public API: the number of days of a year read with `option_env!` in the `day!` macro, the
+    // longest calendar if the year is unknown.
+    #[doc(hidden)]
+    pub const fn __day_count_of(year: Option<&str>) -> u8 {
+        let Some(year) = year else {
+            return LONG_CALENDAR;
+        };
+
+        let bytes = year.as_bytes();
+        let mut value: u16 = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            if !bytes[i].is_ascii_digit() || i >= 4 {
+                return LONG_CALENDAR;
+            }
+            value = value * 10 + (bytes[i] - b'0') as u16;
+            i += 1;
+        }
+
+        Self(value).day_count()
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.trim().parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expecting a year from {FIRST_YEAR} on")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A valid day number of advent (i.e. an integer in range 1 to 25), see [`Year::contains`] for the days of a given
+/// year.
+///
+/// # Display
+/// This value displays as a two digit number.
+///
+/// ```
+/// # use advent_of_code::Day;
+/// let day = Day::new(8).unwrap();
+/// assert_eq!(day.to_string(), "08")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Day(u8);
+
+impl Day {
+    /// Creates a [`Day`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub fn new(day: u8) -> Option<Self> {
+        if day == 0 || day > LONG_CALENDAR {
+            return None;
+        }
+        Some(Self(day))
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(day: u8) -> Self {
+        Self(day)
+    }
+
+    /// Converts the [`Day`] into an [`u8`].
+    pub fn into_inner(self) -> u8 {
+        self.0
+    }
+}
+
+#[cfg(feature = "today")]
+impl Day {
+    /// Returns the current day if a puzzle of `year` unlocked today, `None` otherwise.
+    pub fn today(year: Year) -> Option<Self> {
+        PuzzleId::today()
+            .filter(|id| id.year == year)
+            .map(|id| id.day)
+    }
+}
+
+impl Display for Day {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02}", self.0)
+    }
+}
+
+impl PartialEq<u8> for Day {
+    fn eq(&self, other: &u8) -> bool {
+        self.0.eq(other)
+    }
+}
+
+impl PartialOrd<u8> for Day {
+    fn partial_cmp(&self, other: &u8) -> Option<std::cmp::Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Day {
+    type Err = DayFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let day = s.parse().map_err(|_| DayFromStrError)?;
+        Self::new(day).ok_or(DayFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Day`].
+#[derive(Debug)]
+pub struct DayFromStrError;
+
+impl Error for DayFromStrError {}
+
+impl Display for DayFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a day number between 1 and 25")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A puzzle of a given year, i.e. a [`Day`] which is part of the calendar of its [`Year`].
+///
+/// # Display
+/// This value displays as the year followed by the two digit day.
+///
+/// ```
+/// # use advent_of_code::{PuzzleId, Year, Day};
+/// let id = PuzzleId::new(Year::new(2025).unwrap(), Day::new(8).unwrap()).unwrap();
+/// assert_eq!(id.to_string(), "2025/08");
+/// assert_eq!(PuzzleId::new(Year::new(2025).unwrap(), Day::new(13).unwrap()), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    /// Creates a [`PuzzleId`] if `day` is part of the calendar of `year`, returns [`None`] otherwise.
+    pub fn new(year: Year, day: Day) -> Option<Self> {
+        year.contains(day).then_some(Self { year, day })
+    }
+}
+
+#[cfg(feature = "today")]
+impl PuzzleId {
+    /// Returns the puzzle which unlocked today if it's the advent, `None` otherwise.
+    pub fn today() -> Option<Self> {
+        Self::unlocked_on(server_time())
+    }
+
+    /// Returns the next puzzle of `year` to unlock after `now`, `None` if the whole calendar is unlocked.
+    pub fn next_unlock(year: Year, now: DateTime<FixedOffset>) -> Option<Self> {
+        year.days()
+            .map(|day| Self { year, day })
+            .find(|id| id.unlock_time() > now)
+    }
+
+    /// Returns the time the puzzle unlocks, i.e. midnight in the timezone of the server.
+    pub fn unlock_time(self) -> DateTime<FixedOffset> {
+        server_offset()
+            .with_ymd_and_hms(i32::from(self.year.0), 12, u32::from(self.day.0), 0, 0, 0)
+            .single()
+            .expect("a fixed offset has no ambiguous times")
+    }
+
+    /// The puzzle which unlocked on the date of `now` in the timezone of the server.
+    fn unlocked_on(now: DateTime<FixedOffset>) -> Option<Self> {
+        let now = now.with_timezone(&server_offset());
+        if now.month() != 12 {
+            return None;
+        }
+
+        let year = Year::new(u16::try_from(now.year()).ok()?)?;
+        Self::new(year, Day::new(u8::try_from(now.day()).ok()?)?)
+    }
+}
+
+/// The current time in the timezone of the server, which unlocks puzzles at midnight.
+#[cfg(feature = "today")]
+pub fn server_time() -> DateTime<FixedOffset> {
+    Utc::now().with_timezone(&server_offset())
+}
+
+#[cfg(feature = "today")]
+fn server_offset() -> FixedOffset {
+    FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600).expect("the server offset is less than a day")
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An iterator that yields every day of the calendar of the current project (see [`Year::from_env`]), every day from
+/// the 1st to the 25th if the year is unknown.
+pub fn all_days() -> AllDays {
+    Year::from_env().map_or_else(AllDays::new, Year::days)
+}
+
+/// An iterator that yields every day of a calendar, from the 1st to the 25th by default.
+pub struct AllDays {
+    current: u8,
+    last: u8,
+}
+
+impl AllDays {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            current: 1,
+            last: LONG_CALENDAR,
+        }
+    }
+}
+
+impl Iterator for AllDays {
+    type Item = Day;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current > self.last {
+            return None;
+        }
+        // NOTE: the iterator starts at 1 and the last day of a calendar is never above 25.
+        let day = Day(self.current);
+        self.current += 1;
+
+        Some(day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Day`] value in a const context.
+///
+/// The day must also be part of the calendar of the year set in `AOC_YEAR` when compiling, if any.
+#[macro_export]
+macro_rules! day {
+    ($day:expr) => {{
+        const _ASSERT: () = assert!(
+            $day != 0 && $day <= 25,
+            concat!(
+                "invalid day number `",
+                $day,
+                "`, expecting a value between 1 and 25"
+            ),
+        );
+        const _ASSERT_YEAR: () = assert!(
+            $day <= $crate::template::Year::__day_count_of(option_env!("AOC_YEAR")),
+            concat!(
+                "invalid day number `",
+                $day,
+                "`, it is not part of the calendar of `AOC_YEAR`"
+            ),
+        );
+        $crate::template::Day::__new_unchecked($day)
+    }};
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{AllDays, Day, PuzzleId, Year};
+
+    #[test]
+    fn all_days_iterator() {
+        let mut iter = AllDays::new();
+
+        assert_eq!(iter.next(), Some(Day(1)));
+        assert_eq!(iter.next(), Some(Day(2)));
+        assert_eq!(iter.next(), Some(Day(3)));
+        assert_eq!(iter.next(), Some(Day(4)));
+        assert_eq!(iter.next(), Some(Day(5)));
+        assert_eq!(iter.next(), Some(Day(6)));
+        assert_eq!(iter.next(), Some(Day(7)));
+        assert_eq!(iter.next(), Some(Day(8)));
+        assert_eq!(iter.next(), Some(Day(9)));
+        assert_eq!(iter.next(), Some(Day(10)));
+        assert_eq!(iter.next(), Some(Day(11)));
+        assert_eq!(iter.next(), Some(Day(12)));
+        assert_eq!(iter.next(), Some(Day(13)));
+        assert_eq!(iter.next(), Some(Day(14)));
+        assert_eq!(iter.next(), Some(Day(15)));
+        assert_eq!(iter.next(), Some(Day(16)));
+        assert_eq!(iter.next(), Some(Day(17)));
+        assert_eq!(iter.next(), Some(Day(18)));
+        assert_eq!(iter.next(), Some(Day(19)));
+        assert_eq!(iter.next(), Some(Day(20)));
+        assert_eq!(iter.next(), Some(Day(21)));
+        assert_eq!(iter.next(), Some(Day(22)));
+        assert_eq!(iter.next(), Some(Day(23)));
+        assert_eq!(iter.next(), Some(Day(24)));
+        assert_eq!(iter.next(), Some(Day(25)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn calendars_by_year() {
+        assert_eq!(Year::new(2014), None);
+        assert_eq!(Year(2015).day_count(), 25);
+        assert_eq!(Year(2024).day_count(), 25);
+        assert_eq!(Year(2025).day_count(), 12);
+        assert_eq!(Year(2026).day_count(), 12);
+
+        assert_eq!(Year(2024).days().last(), Some(Day(25)));
+        assert_eq!(Year(2025).days().count(), 12);
+        assert_eq!(Year(2025).days().last(), Some(Day(12)));
+
+        assert_eq!("2025".parse::<Year>().ok(), Some(Year(2025)));
+        assert_eq!("1999".parse::<Year>().is_err(), true);
+    }
+
+    #[test]
+    fn puzzle_ids() {
+        assert_eq!(
+            PuzzleId::new(Year(2024), Day(25)).map(|id| id.to_string()),
+            Some("2024/25".into())
+        );
+        assert_eq!(
+            PuzzleId::new(Year(2025), Day(12)).map(|id| id.to_string()),
+            Some("2025/12".into())
+        );
+        assert_eq!(PuzzleId::new(Year(2025), Day(13)), None);
+    }
+
+    #[test]
+    fn day_count_of_env_value() {
+        assert_eq!(Year::__day_count_of(None), 25);
+        assert_eq!(Year::__day_count_of(Some("2023")), 25);
+        assert_eq!(Year::__day_count_of(Some("2025")), 12);
+        assert_eq!(Year::__day_count_of(Some("not a year")), 25);
+        assert_eq!(Year::__day_count_of(Some("20251")), 25);
+    }
+
+    #[cfg(feature = "today")]
+    #[test]
+    fn unlock_times() {
+        use chrono::DateTime;
+
+        let at = |s: &str| DateTime::parse_from_rfc3339(s).unwrap();
+        let id = |year: u16, day: u8| PuzzleId::new(Year(year), Day(day)).unwrap();
+
+        assert_eq!(id(2024, 5).unlock_time(), at("2024-12-05T05:00:00Z"));
+
+        // 23:59 at the server is still the previous day.
+        assert_eq!(
+            PuzzleId::unlocked_on(at("2024-12-06T04:59:00Z")),
+            Some(id(2024, 5))
+        );
+        assert_eq!(
+            PuzzleId::unlocked_on(at("2024-12-06T05:00:00Z")),
+            Some(id(2024, 6))
+        );
+        assert_eq!(PuzzleId::unlocked_on(at("2025-12-13T12:00:00Z")), None);
+        assert_eq!(PuzzleId::unlocked_on(at("2024-11-30T12:00:00Z")), None);
+
+        assert_eq!(
+            PuzzleId::next_unlock(Year(2024), at("2024-12-06T04:59:00Z")),
+            Some(id(2024, 6))
+        );
+        assert_eq!(
+            PuzzleId::next_unlock(Year(2025), at("2025-06-01T00:00:00Z")),
+            Some(id(2025, 1))
+        );
+        assert_eq!(
+            PuzzleId::next_unlock(Year(2025), at("2025-12-12T05:00:00Z")),
+            None
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
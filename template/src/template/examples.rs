@@ -0,0 +1,275 @@
+/// Extraction of example inputs from the code blocks of downloaded puzzle descriptions.
+///
+/// The example of part one is written to `data/examples/DD.txt`. Part two gets its own `DD-2.txt` if its example
+/// differs from the one of part one. Puzzles often contain more code blocks than just the example, so by default the
+/// first block introduced by a paragraph mentioning an example is picked, and `--example-block <n>` / `--example-2-block <n>`
+/// pick the n-th code block of a part instead.
+///
+/// The answers to the examples are taken from the emphasised code in the text, e.g. `` `*11*` ``, so that scaffolded
+/// tests can assert them.
+use std::{fs, path::PathBuf};
+
+use crate::template::Day;
+
+/// Code blocks picked by the user, counted from 1 within each part.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Selection {
+    pub part_one: Option<usize>,
+    pub part_two: Option<usize>,
+}
+
+impl Selection {
+    fn get(&self, part: u8) -> Option<usize> {
+        match part {
+            1 => self.part_one,
+            _ => self.part_two,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Block {
+    text: String,
+    /// Whether the paragraph before the block mentions an example.
+    is_example: bool,
+}
+
+/// Splits a puzzle description at the heading of part two.
+fn split_parts(markdown: &str) -> [&str; 2] {
+    match markdown.find("## --- Part Two ---") {
+        Some(i) => [&markdown[..i], &markdown[i..]],
+        None => [markdown, ""],
+    }
+}
+
+/// Fenced code blocks of a markdown document, in order.
+fn code_blocks(markdown: &str) -> Vec<Block> {
+    let mut blocks = vec![];
+    let mut text = String::new();
+    let mut code: Option<String> = None;
+
+    for line in markdown.lines() {
+        match (&mut code, line.starts_with("```")) {
+            (None, true) => code = Some(String::new()),
+            (None, false) => {
+                text.push_str(line);
+                text.push('\n');
+            }
+            (Some(block), true) => {
+                let paragraph = text.trim_end().rsplit("\n\n").next().unwrap_or_default();
+                blocks.push(Block {
+                    text: std::mem::take(block),
+                    is_example: paragraph.to_lowercase().contains("example"),
+                });
+                text.clear();
+                code = None;
+            }
+            (Some(block), false) => {
+                block.push_str(line);
+                block.push('\n');
+            }
+        }
+    }
+
+    blocks
+}
+
+/// Picks the example of a part from its code blocks.
+fn pick(blocks: &[Block], choice: Option<usize>) -> Result<Option<&Block>, String> {
+    match choice {
+        Some(n) => blocks
+            .get(n.wrapping_sub(1))
+            .map(Some)
+            .ok_or_else(|| format!("there is no code block {n}, found {}.", blocks.len())),
+        None => Ok(blocks.iter().find(|b| b.is_example).or(blocks.first())),
+    }
+}
+
+/// Answer to the example of each part: the last emphasised code of the part's text that is a number.
+pub fn answers(markdown: &str) -> [Option<String>; 2] {
+    split_parts(markdown).map(|part| {
+        let text = code_blocks_removed(part);
+
+        [("`*", "*`"), ("*`", "`*")]
+            .into_iter()
+            .flat_map(|(open, close)| {
+                let text = &text;
+                text.match_indices(open).filter_map(move |(i, _)| {
+                    let start = i + open.len();
+                    let end = start + text[start..].find(close)?;
+                    Some((i, &text[start..end]))
+                })
+            })
+            .filter(|(_, code)| code.parse::<i64>().is_ok())
+            .max_by_key(|(i, _)| *i)
+            .map(|(_, code)| code.to_string())
+    })
+}
+
+/// Text of a markdown document without its code blocks, which are not emphasised.
+fn code_blocks_removed(markdown: &str) -> String {
+    markdown
+        .split("```")
+        .step_by(2)
+        .collect::<Vec<_>>()
+        .join("")
+}
+
+/// Parts with an example file of their own, `data/examples/DD-N.txt`, which is read by `read_file_part`.
+pub fn part_files(day: Day) -> Vec<u8> {
+    let Ok(entries) = fs::read_dir(PathBuf::from("data").join("examples")) else {
+        return vec![];
+    };
+
+    let prefix = format!("{day}-");
+    let mut parts: Vec<u8> = entries
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            name.strip_prefix(&prefix)?
+                .strip_suffix(".txt")?
+                .parse()
+                .ok()
+        })
+        .collect();
+
+    parts.sort_unstable();
+    parts
+}
+
+fn get_example_path(day: Day, part: u8) -> PathBuf {
+    let examples = PathBuf::from("data").join("examples");
+    match part {
+        1 => examples.join(format!("{day}.txt")),
+        _ => examples.join(format!("{day}-{part}.txt")),
+    }
+}
+
+/// Writes the examples of a puzzle description. Files that already have content are only replaced if a block was
+/// picked explicitly, so that examples edited by hand are kept.
+pub fn write(day: Day, markdown: &str, selection: &Selection) {
+    let mut previous: Option<String> = None;
+
+    for (part, markdown) in (1..=2).zip(split_parts(markdown)) {
+        let blocks = code_blocks(markdown);
+        let choice = selection.get(part);
+        let flag = match part {
+            1 => "--example-block",
+            _ => "--example-2-block",
+        };
+
+        let block = match pick(&blocks, choice) {
+            Ok(Some(block)) => block,
+            Ok(None) => continue,
+            Err(e) => {
+                eprintln!("Could not pick the example of part {part}: {e}");
+                continue;
+            }
+        };
+
+        // part two mostly reuses the example of part one, which needs no file of its own.
+        if part == 2 && choice.is_none() && previous.as_ref() == Some(&block.text) {
+            continue;
+        }
+        previous = Some(block.text.clone());
+
+        let path = get_example_path(day, part);
+        let has_content = fs::read_to_string(&path).is_ok_and(|x| !x.trim().is_empty());
+        if has_content && choice.is_none() {
+            continue;
+        }
+
+        if let Err(e) =
+            fs::create_dir_all(path.parent().unwrap()).and_then(|()| fs::write(&path, &block.text))
+        {
+            eprintln!("Failed to write example file: {e}");
+            continue;
+        }
+
+        println!("🎄 Successfully wrote example to \"{}\".", path.display());
+        if blocks.len() > 1 && choice.is_none() {
+            let n = blocks.iter().position(|b| b == block).unwrap() + 1;
+            println!(
+                "   Picked code block {n} of {} in part {part}, pass `{flag} <n>` to pick another one.",
+                blocks.len()
+            );
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{answers, code_blocks, pick, split_parts, Block};
+
+    const PUZZLE: &str = "## --- Day 1: Test ---
+
+The grid looks like this:
+
+```
+#.#
+```
+
+For example:
+
+```
+3   4
+4   3
+```
+
+In this example, the total is `*11*`. The `*#*` grid
+does not count.
+
+## --- Part Two ---
+
+Consider the same example again.
+
+```
+3   4
+4   3
+```
+";
+
+    fn block(text: &str, is_example: bool) -> Block {
+        Block {
+            text: text.to_string(),
+            is_example,
+        }
+    }
+
+    #[test]
+    fn finds_code_blocks_per_part() {
+        let [part_one, part_two] = split_parts(PUZZLE);
+
+        assert_eq!(
+            code_blocks(part_one),
+            vec![block("#.#\n", false), block("3   4\n4   3\n", true)]
+        );
+        assert_eq!(code_blocks(part_two), vec![block("3   4\n4   3\n", true)]);
+        assert_eq!(split_parts("no part two")[1], "");
+    }
+
+    #[test]
+    fn picks_examples() {
+        let blocks = code_blocks(split_parts(PUZZLE)[0]);
+
+        assert_eq!(pick(&blocks, None), Ok(Some(&blocks[1])));
+        assert_eq!(pick(&blocks, Some(1)), Ok(Some(&blocks[0])));
+        assert_eq!(pick(&blocks, Some(0)).is_err(), true);
+        assert_eq!(pick(&blocks, Some(3)).is_err(), true);
+
+        // falls back to the first block if no paragraph mentions an example.
+        let blocks = code_blocks("Look:\n\n```\na\n```\n\n```\nb\n```\n");
+        assert_eq!(pick(&blocks, None), Ok(Some(&blocks[0])));
+        assert_eq!(pick(&[], None), Ok(None));
+    }
+
+    #[test]
+    fn finds_example_answers() {
+        assert_eq!(answers(PUZZLE), [Some("11".into()), None]);
+        assert_eq!(
+            answers("The result is *`31`*.\n\n## --- Part Two ---\n\n```\n`*3*`\n```\n\nNow it is `*-4*`."),
+            [Some("31".into()), Some("-4".into())]
+        );
+    }
+}
@@ -0,0 +1,357 @@
+/// Exports timings to a file, see `cargo time --export <format> <path>`.
+///
+/// Unlike `--store`, this leaves the readme and `timings.json` alone, so results can be published or compared
+/// elsewhere.
+use std::{fmt::Display, fs, io, path::PathBuf, str::FromStr};
+
+use tinyjson::JsonValue;
+
+use crate::template::{
+    readme_benchmarks,
+    report::Step,
+    timings::{Timing, Timings},
+    Year,
+};
+
+/// Number of days highlighted as the slowest in the HTML report.
+const SLOWEST_DAYS: usize = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// One row per day, with the formatted and the raw runtime of every step.
+    Csv,
+    /// One timing per line, in the format of `timings.json`.
+    JsonLines,
+    /// The table of the readme as a document of its own, e.g. `BENCHMARKS.md`.
+    Markdown,
+    /// A self-contained page with a sortable table that highlights the slowest days.
+    Html,
+}
+
+/// Where `cargo time --export` writes to.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Export {
+    pub format: ExportFormat,
+    pub path: PathBuf,
+}
+
+impl Export {
+    /// Writes the timings to the file in the selected format.
+    pub fn write(&self, timings: &Timings) -> Result<(), io::Error> {
+        let content = match self.format {
+            ExportFormat::Csv => to_csv(timings),
+            ExportFormat::JsonLines => to_json_lines(timings)?,
+            ExportFormat::Markdown => to_markdown(timings),
+            ExportFormat::Html => to_html(timings),
+        };
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.path, content)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn step_text(timing: &Timing, step: Step) -> Option<&str> {
+    match step {
+        Step::Parse => timing.parse.as_deref(),
+        Step::Part(1) => timing.part_1.as_deref(),
+        Step::Part(2) => timing.part_2.as_deref(),
+        Step::Part(_) => None,
+    }
+}
+
+const STEPS: [Step; 3] = [Step::Parse, Step::Part(1), Step::Part(2)];
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+fn to_csv(timings: &Timings) -> String {
+    let mut lines = vec![
+        "day,parse,part_1,part_2,parse_nanos,part_1_nanos,part_2_nanos,total_nanos,status"
+            .to_string(),
+    ];
+
+    for timing in &timings.data {
+        let mut fields = vec![timing.day.to_string()];
+        fields.extend(STEPS.map(|step| csv_field(step_text(timing, step).unwrap_or_default())));
+        fields.extend(STEPS.map(|step| {
+            timing
+                .step_nanos(step)
+                .map_or_else(String::new, |n| n.to_string())
+        }));
+        fields.push(timing.total_nanos.to_string());
+        fields.push(
+            timing
+                .status
+                .as_ref()
+                .map_or_else(String::new, ToString::to_string),
+        );
+        lines.push(fields.join(","));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+fn to_json_lines(timings: &Timings) -> Result<String, io::Error> {
+    timings
+        .data
+        .iter()
+        .map(|timing| {
+            JsonValue::from(timing)
+                .stringify()
+                .map(|line| line + "\n")
+                .map_err(|e| io::Error::other(e.to_string()))
+        })
+        .collect()
+}
+
+fn to_markdown(timings: &Timings) -> String {
+    readme_benchmarks::construct_table("#", timings.clone(), timings.total_millis(), &[]) + "\n"
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// A table cell that sorts by `value` rather than by its text.
+fn html_cell(text: impl Display, value: impl Display) -> String {
+    format!(
+        "<td data-value=\"{value}\">{}</td>",
+        escape_html(&text.to_string())
+    )
+}
+
+fn to_html(timings: &Timings) -> String {
+    let title = match Year::from_env() {
+        Some(year) => format!("Advent of Code {year} benchmarks"),
+        None => "Advent of Code benchmarks".into(),
+    };
+    let total_nanos: f64 = timings.data.iter().map(|t| t.total_nanos).sum();
+
+    let mut by_runtime: Vec<&Timing> = timings.data.iter().collect();
+    by_runtime.sort_unstable_by(|a, b| b.total_nanos.total_cmp(&a.total_nanos));
+    let slowest: Vec<&Timing> = by_runtime
+        .into_iter()
+        .filter(|t| t.total_nanos > 0.0)
+        .take(SLOWEST_DAYS)
+        .collect();
+
+    let mut rows = vec![];
+    for timing in &timings.data {
+        let missing = timing.status.as_ref().map_or("-", |s| s.describe());
+        let mut cells = vec![html_cell(timing.day, timing.day.into_inner())];
+
+        for step in STEPS {
+            let text = step_text(timing, step).unwrap_or(match step {
+                Step::Parse => "-",
+                Step::Part(_) => missing,
+            });
+            // steps without a runtime sort before all others.
+            cells.push(html_cell(text, timing.step_nanos(step).unwrap_or(-1.0)));
+        }
+
+        let share = if total_nanos > 0.0 {
+            timing.total_nanos / total_nanos * 100.0
+        } else {
+            0.0
+        };
+        cells.push(html_cell(
+            format!("{:.2}ms", timing.total_nanos / 1_000_000.0),
+            timing.total_nanos,
+        ));
+        cells.push(format!(
+            "<td data-value=\"{share}\"><span class=\"bar\" style=\"width: {share:.1}%\"></span>{share:.1}%</td>"
+        ));
+
+        let class = if slowest.iter().any(|t| t.day == timing.day) {
+            " class=\"slowest\""
+        } else {
+            ""
+        };
+        rows.push(format!("      <tr{class}>{}</tr>", cells.join("")));
+    }
+
+    HTML_TEMPLATE
+        .replace("%TITLE%", &escape_html(&title))
+        .replace("%TOTAL%", &format!("{:.2}ms", total_nanos / 1_000_000.0))
+        .replace("%ROWS%", &rows.join("\n"))
+}
+
+const HTML_TEMPLATE: &str = r#"<!DOCTYPE html>
+<html lang="en">
+  <head>
+    <meta charset="utf-8">
+    <title>%TITLE%</title>
+    <style>
+      body { font-family: system-ui, sans-serif; margin: 2rem auto; max-width: 60rem; color: #222; }
+      table { border-collapse: collapse; width: 100%; }
+      th, td { padding: 0.4rem 0.8rem; text-align: right; border-bottom: 1px solid #ddd; }
+      th { cursor: pointer; user-select: none; background: #f4f4f4; }
+      th[aria-sort="ascending"]::after { content: " ▲"; }
+      th[aria-sort="descending"]::after { content: " ▼"; }
+      tr.slowest { background: #fde2e1; font-weight: bold; }
+      td:last-child { position: relative; min-width: 8rem; }
+      .bar { position: absolute; left: 0; top: 15%; height: 70%; background: #f3a5a0; opacity: 0.5; }
+    </style>
+  </head>
+  <body>
+    <h1>%TITLE%</h1>
+    <p>Total: <strong>%TOTAL%</strong>. The slowest days are highlighted, click a column to sort by it.</p>
+    <table>
+      <thead>
+        <tr><th>Day</th><th>Parse</th><th>Part 1</th><th>Part 2</th><th>Total</th><th>Share</th></tr>
+      </thead>
+      <tbody>
+%ROWS%
+      </tbody>
+    </table>
+    <script>
+      document.querySelectorAll("th").forEach((header, column) => {
+        header.addEventListener("click", () => {
+          const ascending = header.getAttribute("aria-sort") !== "ascending";
+          document.querySelectorAll("th").forEach((th) => th.removeAttribute("aria-sort"));
+          header.setAttribute("aria-sort", ascending ? "ascending" : "descending");
+
+          const body = document.querySelector("tbody");
+          const value = (row) => parseFloat(row.children[column].dataset.value);
+          [...body.rows]
+            .sort((a, b) => (ascending ? 1 : -1) * (value(a) - value(b)))
+            .forEach((row) => body.appendChild(row));
+        });
+      });
+    </script>
+  </body>
+</html>
+"#;
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(ExportFormat::Csv),
+            "jsonl" => Ok(ExportFormat::JsonLines),
+            "md" | "markdown" => Ok(ExportFormat::Markdown),
+            "html" => Ok(ExportFormat::Html),
+            _ => Err(format!(
+                "unknown export format `{s}`, expected csv, jsonl, md or html."
+            )),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{to_csv, to_html, to_json_lines, to_markdown, ExportFormat};
+    use crate::{
+        day,
+        template::{
+            report::{Status, Step},
+            timings::{Timing, Timings},
+            Day,
+        },
+    };
+
+    fn timing(day: Day, part_1: f64, part_2: Option<f64>) -> Timing {
+        let mut nanos = vec![(Step::Part(1), part_1)];
+        nanos.extend(part_2.map(|nanos| (Step::Part(2), nanos)));
+
+        Timing {
+            day,
+            parse: None,
+            part_1: Some(format!("{part_1}ns")),
+            part_2: part_2.map(|nanos| format!("{nanos}ns")),
+            total_nanos: part_1 + part_2.unwrap_or_default(),
+            status: part_2.is_none().then_some(Status::Panicked),
+            allocs: vec![],
+            nanos,
+        }
+    }
+
+    fn get_mock_timings() -> Timings {
+        Timings {
+            history: vec![],
+            data: vec![
+                timing(day!(1), 10.0, Some(20.0)),
+                timing(day!(2), 1000.0, Some(3000.0)),
+                timing(day!(3), 5.0, None),
+                timing(day!(4), 100.0, Some(100.0)),
+                timing(day!(5), 1.0, Some(1.0)),
+            ],
+        }
+    }
+
+    #[test]
+    fn parses_formats() {
+        assert_eq!("csv".parse(), Ok(ExportFormat::Csv));
+        assert_eq!("jsonl".parse(), Ok(ExportFormat::JsonLines));
+        assert_eq!("md".parse(), Ok(ExportFormat::Markdown));
+        assert_eq!("html".parse(), Ok(ExportFormat::Html));
+        assert_eq!("xml".parse::<ExportFormat>().is_err(), true);
+    }
+
+    #[test]
+    fn exports_csv() {
+        let csv = to_csv(&get_mock_timings());
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), 6);
+        assert_eq!(
+            lines[0],
+            "day,parse,part_1,part_2,parse_nanos,part_1_nanos,part_2_nanos,total_nanos,status"
+        );
+        assert_eq!(lines[1], "01,,10ns,20ns,,10,20,30,");
+        assert_eq!(lines[3], "03,,5ns,,,5,,5,panic");
+    }
+
+    #[test]
+    fn exports_json_lines() {
+        let jsonl = to_json_lines(&get_mock_timings()).unwrap();
+        assert_eq!(jsonl.lines().count(), 5);
+        assert_eq!(jsonl.lines().all(|line| line.starts_with('{')), true);
+        assert_eq!(jsonl.contains(r#""day":"02""#), true);
+    }
+
+    #[test]
+    fn exports_markdown() {
+        let markdown = to_markdown(&get_mock_timings());
+        assert_eq!(markdown.contains("# Benchmarks"), true);
+        assert_eq!(
+            markdown.contains("| [Day 3](./src/bin/03.rs) | `-` | `5ns` | `panicked` |"),
+            true
+        );
+    }
+
+    #[test]
+    fn highlights_slowest_days_in_html() {
+        let html = to_html(&get_mock_timings());
+        let slowest: Vec<&str> = html
+            .lines()
+            .filter(|line| line.contains("class=\"slowest\""))
+            .collect();
+
+        assert_eq!(slowest.len(), 3);
+        assert_eq!(slowest[0].contains(">01<"), true);
+        assert_eq!(slowest[1].contains(">02<"), true);
+        assert_eq!(slowest[2].contains(">04<"), true);
+        assert_eq!(html.contains("<td data-value=\"-1\">-</td>"), true);
+        assert_eq!(html.contains("%ROWS%"), false);
+    }
+}
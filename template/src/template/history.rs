@@ -0,0 +1,372 @@
+/// History of the benchmark runs stored in `timings.json`, and the comparison of new timings against it
+/// (see `cargo time --compare`).
+use std::{collections::HashMap, process::Command};
+
+use tinyjson::JsonValue;
+
+use crate::template::{
+    report::{format_nanos, Step},
+    submissions,
+    timings::Timing,
+    Day, ANSI_BOLD, ANSI_RESET,
+};
+
+/// Steps that got slower by more than this many percent fail `cargo time --compare`, unless set with `--threshold`.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// Stored runs kept per day, older runs of a day are dropped from the history.
+pub const RUNS_PER_DAY: usize = 50;
+
+/// A benchmark run stored with `cargo time --store`.
+#[derive(Clone, Debug)]
+pub struct Run {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// Short hash of the commit the run was made at, with a `-dirty` suffix if tracked files had changes.
+    /// `None` outside of a git repository.
+    pub commit: Option<String>,
+    pub data: Vec<Timing>,
+}
+
+impl Run {
+    /// A run of `data` made now, at the commit that is checked out.
+    pub fn new(data: Vec<Timing>) -> Self {
+        Self {
+            timestamp: submissions::now(),
+            commit: current_commit(),
+            data,
+        }
+    }
+}
+
+/// Runs git and returns its trimmed output, [`None`] if it failed, e.g. outside of a git repository.
+pub fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn current_commit() -> Option<String> {
+    let commit = git(&["rev-parse", "--short", "HEAD"])?;
+    let dirty = git(&["status", "--porcelain", "--untracked-files=no"])
+        .is_some_and(|status| !status.is_empty());
+
+    Some(if dirty {
+        format!("{commit}-dirty")
+    } else {
+        commit
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The stored run new timings are compared against.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Baseline {
+    /// The latest run of each day.
+    Previous,
+    /// The latest run of each day made at this commit, a full hash as returned by [`resolve_baseline`].
+    Commit(String),
+}
+
+/// Resolves the revision of a [`Baseline::Commit`], e.g. `main` or `HEAD~1`, to the full hash of its commit.
+pub fn resolve_baseline(baseline: &Baseline) -> Result<Baseline, String> {
+    match baseline {
+        Baseline::Previous => Ok(Baseline::Previous),
+        Baseline::Commit(rev) => git(&[
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("{rev}^{{commit}}"),
+        ])
+        .map(Baseline::Commit)
+        .ok_or_else(|| format!("unknown revision `{rev}`.")),
+    }
+}
+
+/// Settings of `cargo time --compare`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompareConfig {
+    pub baseline: Baseline,
+    /// Percentage by which a step may get slower before it counts as a regression.
+    pub threshold: f64,
+}
+
+/// Runtime of a step in a new run and in the run it is compared against.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Delta {
+    pub day: Day,
+    pub step: Step,
+    pub before_nanos: f64,
+    pub after_nanos: f64,
+    /// Commit of the run the new timing is compared against.
+    pub commit: Option<String>,
+}
+
+impl Delta {
+    /// Relative change of the runtime in percent, positive if the step got slower.
+    pub fn percent(&self) -> f64 {
+        (self.after_nanos - self.before_nanos) / self.before_nanos * 100.0
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.percent() > threshold
+    }
+}
+
+/// Compares every step of `new` against the same step of the latest matching run in `history`.
+/// Days and steps without a stored runtime are left out.
+pub fn compare(history: &[Run], new: &[Timing], baseline: &Baseline) -> Vec<Delta> {
+    let mut deltas = vec![];
+
+    for timing in new {
+        let reference = history
+            .iter()
+            .rev()
+            .filter(|run| match baseline {
+                Baseline::Previous => true,
+                // runs store the short hash, which may be shorter than the ones git abbreviates to today.
+                Baseline::Commit(hash) => run.commit.as_deref().is_some_and(|commit| {
+                    let commit = commit.trim_end_matches("-dirty");
+                    !commit.is_empty() && hash.starts_with(commit)
+                }),
+            })
+            .find_map(|run| {
+                let before = run.data.iter().find(|t| t.day == timing.day)?;
+                Some((run, before))
+            });
+
+        let Some((run, before)) = reference else {
+            continue;
+        };
+
+        for (step, after_nanos) in &timing.nanos {
+            let Some(before_nanos) = before.step_nanos(*step) else {
+                continue;
+            };
+
+            deltas.push(Delta {
+                day: timing.day,
+                step: *step,
+                before_nanos,
+                after_nanos: *after_nanos,
+                commit: run.commit.clone(),
+            });
+        }
+    }
+
+    deltas
+}
+
+/// Prints the deltas and returns whether any step regressed past the threshold.
+pub fn print_deltas(deltas: &[Delta], threshold: f64) -> bool {
+    println!("\n{ANSI_BOLD}Compared to stored runs{ANSI_RESET} (threshold: {threshold}%)");
+
+    if deltas.is_empty() {
+        println!("No stored runs to compare against.");
+        return false;
+    }
+
+    let mut regressed = false;
+    for delta in deltas {
+        let step = delta.step.label();
+        let is_regression = delta.is_regression(threshold);
+        regressed |= is_regression;
+        let marker = if is_regression {
+            " ⚠️ regression"
+        } else {
+            ""
+        };
+
+        println!(
+            "Day {} {step}: {} → {} ({:+.1}%) vs {}{marker}",
+            delta.day,
+            format_nanos(delta.before_nanos),
+            format_nanos(delta.after_nanos),
+            delta.percent(),
+            delta.commit.as_deref().unwrap_or("unknown commit"),
+        );
+    }
+
+    regressed
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Run> for JsonValue {
+    fn from(value: &Run) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert(
+            "commit".into(),
+            value
+                .commit
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Run {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected run to be a JSON object.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|timestamp| *timestamp as u64)
+            .ok_or("Expected run.timestamp to be a number.")?;
+
+        let commit = json.get("commit").and_then(|v| v.get::<String>()).cloned();
+
+        let data = json
+            .get("data")
+            .and_then(|v| v.get::<Vec<JsonValue>>())
+            .ok_or("Expected run.data to be an array.")?
+            .iter()
+            .map(Timing::try_from)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Run {
+            timestamp,
+            commit,
+            data,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{compare, git, resolve_baseline, Baseline, Delta, Run};
+    use crate::{
+        day,
+        template::{report::Step, timings::Timing, Day},
+    };
+
+    fn timing(day: Day, part_1: f64, part_2: f64) -> Timing {
+        Timing {
+            day,
+            parse: None,
+            part_1: None,
+            part_2: None,
+            total_nanos: part_1 + part_2,
+            status: None,
+            allocs: vec![],
+            nanos: vec![(Step::Part(1), part_1), (Step::Part(2), part_2)],
+        }
+    }
+
+    fn run(commit: &str, data: Vec<Timing>) -> Run {
+        Run {
+            timestamp: 0,
+            commit: Some(commit.into()),
+            data,
+        }
+    }
+
+    fn history() -> Vec<Run> {
+        vec![
+            run("aaaaaaa", vec![timing(day!(1), 100.0, 200.0)]),
+            run(
+                "bbbbbbb",
+                vec![timing(day!(1), 200.0, 200.0), timing(day!(2), 10.0, 0.0)],
+            ),
+        ]
+    }
+
+    #[test]
+    fn compares_against_previous_run() {
+        let deltas = compare(
+            &history(),
+            &[timing(day!(1), 250.0, 100.0), timing(day!(3), 1.0, 1.0)],
+            &Baseline::Previous,
+        );
+
+        assert_eq!(
+            deltas,
+            vec![
+                Delta {
+                    day: day!(1),
+                    step: Step::Part(1),
+                    before_nanos: 200.0,
+                    after_nanos: 250.0,
+                    commit: Some("bbbbbbb".into()),
+                },
+                Delta {
+                    day: day!(1),
+                    step: Step::Part(2),
+                    before_nanos: 200.0,
+                    after_nanos: 100.0,
+                    commit: Some("bbbbbbb".into()),
+                },
+            ]
+        );
+        assert_eq!(deltas[0].percent(), 25.0);
+        assert_eq!(deltas[0].is_regression(10.0), true);
+        assert_eq!(deltas[0].is_regression(30.0), false);
+        assert_eq!(deltas[1].is_regression(10.0), false);
+    }
+
+    #[test]
+    fn compares_against_baseline_commit() {
+        let deltas = compare(
+            &history(),
+            &[timing(day!(1), 150.0, 200.0), timing(day!(2), 10.0, 5.0)],
+            &Baseline::Commit("aaaaaaa0123456789abcdef0123456789abcdef".into()),
+        );
+
+        assert_eq!(deltas.len(), 2);
+        assert_eq!(deltas[0].percent(), 50.0);
+        assert_eq!(deltas[1].percent(), 0.0);
+        assert_eq!(deltas[0].commit, Some("aaaaaaa".into()));
+    }
+
+    #[test]
+    fn resolves_baseline_revisions() {
+        let head = git(&["rev-parse", "HEAD"]).unwrap();
+
+        for rev in [head.clone(), head[..7].to_string(), "HEAD".to_string()] {
+            assert_eq!(
+                resolve_baseline(&Baseline::Commit(rev)),
+                Ok(Baseline::Commit(head.clone()))
+            );
+        }
+        assert_eq!(
+            resolve_baseline(&Baseline::Commit("no-such-revision".into())).is_err(),
+            true
+        );
+    }
+
+    #[test]
+    fn skips_steps_without_stored_runtime() {
+        let deltas = compare(
+            &history(),
+            &[timing(day!(2), 10.0, 5.0)],
+            &Baseline::Previous,
+        );
+
+        // part two of the stored run took no time, so there is nothing to compare to.
+        assert_eq!(deltas.len(), 1);
+        assert_eq!(deltas[0].step, Step::Part(1));
+    }
+}
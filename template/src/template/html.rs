@@ -0,0 +1,165 @@
+/// Minimal conversion of Advent of Code pages from HTML, just enough for puzzle descriptions and submission responses.
+use std::iter;
+
+/// Converts the puzzle descriptions of a page, i.e. its `<article>` elements, to markdown.
+pub fn to_markdown(html: &str) -> String {
+    let markdown: Vec<String> = articles(html).map(convert).collect();
+    format!("{}\n", markdown.join("\n\n"))
+}
+
+/// Plain text of the first `<article>` of a page, which holds the response to a submission.
+pub fn article_text(html: &str) -> String {
+    let article = articles(html).next().unwrap_or(html);
+
+    let text: String = tokens(article)
+        .filter_map(|token| match token {
+            Token::Text(text) => Some(unescape(text)),
+            Token::Tag(_) => None,
+        })
+        .collect();
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn articles(html: &str) -> impl Iterator<Item = &str> {
+    html.split("<article").skip(1).filter_map(|rest| {
+        let start = rest.find('>')? + 1;
+        let end = rest.find("</article>")?;
+        rest.get(start..end)
+    })
+}
+
+enum Token<'a> {
+    /// Contents of a tag without the angle brackets, e.g. `a href="/2024"` or `/p`.
+    Tag(&'a str),
+    Text(&'a str),
+}
+
+fn tokens(html: &str) -> impl Iterator<Item = Token<'_>> {
+    let mut rest = html;
+
+    iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+
+        if let Some(tag) = rest.strip_prefix('<') {
+            if let Some(end) = tag.find('>') {
+                rest = &tag[end + 1..];
+                return Some(Token::Tag(&tag[..end]));
+            }
+        }
+
+        // text runs until the next tag, a stray `<` at the start is kept as text.
+        let end = rest
+            .char_indices()
+            .skip(1)
+            .find(|(_, c)| *c == '<')
+            .map_or(rest.len(), |(i, _)| i);
+        let (text, remaining) = rest.split_at(end);
+        rest = remaining;
+        Some(Token::Text(text))
+    })
+}
+
+fn convert(article: &str) -> String {
+    let mut markdown = String::new();
+    let mut in_pre = false;
+    let mut links: Vec<String> = vec![];
+
+    for token in tokens(article) {
+        let tag = match token {
+            Token::Text(text) => {
+                markdown.push_str(&unescape(text));
+                continue;
+            }
+            Token::Tag(tag) => tag,
+        };
+
+        let name = tag.split_whitespace().next().unwrap_or_default();
+
+        match (name, in_pre) {
+            ("h2", _) => markdown.push_str("## "),
+            ("/h2" | "/p" | "/ul", _) => markdown.push_str("\n\n"),
+            ("pre", _) => {
+                markdown.push_str("```\n");
+                in_pre = true;
+            }
+            ("/pre", _) => {
+                if !markdown.ends_with('\n') {
+                    markdown.push('\n');
+                }
+                markdown.push_str("```\n\n");
+                in_pre = false;
+            }
+            // inline formatting is dropped inside code blocks.
+            ("code" | "/code", false) => markdown.push('`'),
+            ("em" | "/em", false) => markdown.push('*'),
+            ("li", _) => markdown.push_str("- "),
+            ("/li", _) => markdown.push('\n'),
+            ("a", _) => {
+                links.push(attribute(tag, "href").unwrap_or_default().to_string());
+                markdown.push('[');
+            }
+            ("/a", _) => {
+                let href = links.pop().unwrap_or_default();
+                markdown.push_str(&format!("]({href})"));
+            }
+            _ => {}
+        }
+    }
+
+    // paragraphs and lists may both end a block, which would leave more than one empty line.
+    while markdown.contains("\n\n\n") {
+        markdown = markdown.replace("\n\n\n", "\n\n");
+    }
+
+    markdown.trim().to_string()
+}
+
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = tag[start..].find('"')?;
+    Some(&tag[start..start + end])
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{article_text, to_markdown};
+
+    #[test]
+    fn converts_puzzle_descriptions() {
+        let html = r#"<main><article class="day-desc"><h2>--- Day 1: Test ---</h2>
+<p>Find the <em>sum</em> of <code>a &lt; b</code>, see <a href="/2024/about">about</a>.</p>
+<pre><code>3   4
+<em>4</em>   3
+</code></pre>
+<ul><li>one</li><li>two</li></ul>
+</article><p>Your puzzle answer was <code>11</code>.</p><article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Again.</p></article></main>"#;
+
+        assert_eq!(
+            to_markdown(html),
+            "## --- Day 1: Test ---\n\nFind the *sum* of `a < b`, see [about](/2024/about).\n\n```\n3   4\n4   3\n```\n\n- one\n- two\n\n## --- Part Two ---\n\nAgain.\n"
+        );
+    }
+
+    #[test]
+    fn extracts_response_text() {
+        let html = r#"<main><article><p>That's not the right answer; your answer is too high.  <a href="/2024/day/1">[Return to Day 1]</a></p></article></main>"#;
+        assert_eq!(
+            article_text(html),
+            "That's not the right answer; your answer is too high. [Return to Day 1]"
+        );
+    }
+}
@@ -0,0 +1,167 @@
+/// Resource limits for solution binaries spawned by `run_multi`.
+use std::{
+    process::{Child, Command, ExitStatus},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::template::report::Status;
+
+/// How often a running child is polled for completion while a timeout is set.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Limits applied to a single solution run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Limits {
+    /// Wall-clock time a solution may run for before it is killed.
+    pub timeout: Option<Duration>,
+    /// Address space limit in bytes. Only applied on Linux.
+    pub memory: Option<u64>,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            timeout: Some(Duration::from_secs(300)),
+            memory: None,
+        }
+    }
+}
+
+impl Limits {
+    /// Builds limits from `--timeout <secs>` (`0` disables it) and `--memory-limit <MiB>`.
+    pub fn from_args(timeout_secs: Option<u64>, memory_mib: Option<u64>) -> Self {
+        Self {
+            timeout: match timeout_secs {
+                Some(0) => None,
+                Some(secs) => Some(Duration::from_secs(secs)),
+                None => Self::default().timeout,
+            },
+            memory: memory_mib.map(|mib| mib * 1024 * 1024),
+        }
+    }
+
+    /// Applies the memory limit to a command before it is spawned.
+    #[cfg(target_os = "linux")]
+    pub(crate) fn apply(&self, cmd: &mut Command) {
+        use std::os::unix::process::CommandExt;
+
+        if let Some(bytes) = self.memory {
+            let limit = libc::rlimit {
+                rlim_cur: bytes,
+                rlim_max: bytes,
+            };
+
+            // SAFETY: `setrlimit` is async-signal-safe and only touches the forked child.
+            unsafe {
+                cmd.pre_exec(move || {
+                    if libc::setrlimit(libc::RLIMIT_AS, &limit) != 0 {
+                        return Err(std::io::Error::last_os_error());
+                    }
+                    Ok(())
+                });
+            }
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub(crate) fn apply(&self, _cmd: &mut Command) {}
+
+    /// Waits for a child to exit, killing it once the timeout has passed.
+    /// Returns [`Status::TimedOut`] or [`Status::OutOfMemory`] if the child was stopped by a limit.
+    pub(crate) fn wait(&self, child: &mut Child) -> std::io::Result<Option<Status>> {
+        let Some(timeout) = self.timeout else {
+            let status = child.wait()?;
+            return Ok(self.classify(status));
+        };
+
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            if let Some(status) = child.try_wait()? {
+                return Ok(self.classify(status));
+            }
+
+            if Instant::now() >= deadline {
+                child.kill()?;
+                child.wait()?;
+                return Ok(Some(Status::TimedOut));
+            }
+
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    /// Rust aborts the process when an allocation fails, so an abort under a memory limit is reported as running out of memory.
+    fn classify(&self, status: ExitStatus) -> Option<Status> {
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::ExitStatusExt;
+
+            if self.memory.is_some() && status.signal() == Some(libc::SIGABRT) {
+                return Some(Status::OutOfMemory);
+            }
+        }
+
+        #[cfg(not(unix))]
+        let _ = status;
+
+        None
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Limits;
+    use std::time::Duration;
+
+    #[test]
+    fn defaults_to_timeout() {
+        let limits = Limits::from_args(None, None);
+        assert_eq!(limits.timeout, Some(Duration::from_secs(300)));
+        assert_eq!(limits.memory, None);
+    }
+
+    #[test]
+    fn disables_timeout() {
+        let limits = Limits::from_args(Some(0), Some(512));
+        assert_eq!(limits.timeout, None);
+        assert_eq!(limits.memory, Some(512 * 1024 * 1024));
+    }
+
+    #[cfg(unix)]
+    mod wait {
+        use crate::template::{limits::Limits, report::Status};
+        use std::{os::unix::process::ExitStatusExt, process::Command, time::Duration};
+
+        #[test]
+        fn kills_on_timeout() {
+            let limits = Limits {
+                timeout: Some(Duration::from_millis(50)),
+                memory: None,
+            };
+            let mut child = Command::new("sleep").arg("5").spawn().unwrap();
+            assert_eq!(limits.wait(&mut child).unwrap(), Some(Status::TimedOut));
+        }
+
+        #[test]
+        fn passes_through_normal_exits() {
+            let limits = Limits::default();
+            let mut child = Command::new("true").spawn().unwrap();
+            assert_eq!(limits.wait(&mut child).unwrap(), None);
+        }
+
+        #[test]
+        fn classifies_aborts_under_memory_limit() {
+            let limits = Limits {
+                timeout: None,
+                memory: Some(1024),
+            };
+            let aborted = std::process::ExitStatus::from_raw(libc::SIGABRT);
+            assert_eq!(limits.classify(aborted), Some(Status::OutOfMemory));
+            assert_eq!(Limits::default().classify(aborted), None);
+        }
+    }
+}
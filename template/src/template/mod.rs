@@ -0,0 +1,226 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+pub mod alloc_counter;
+pub mod aoc_client;
+pub mod cli;
+pub mod commands;
+pub mod examples;
+pub mod limits;
+pub mod registry;
+pub mod runner;
+
+pub use day::*;
+
+mod ab_bench;
+mod answers;
+mod charts;
+mod dashboard;
+mod day;
+mod export;
+mod history;
+mod html;
+mod readme_benchmarks;
+mod report;
+mod run_multi;
+mod stats;
+mod submissions;
+mod timings;
+
+pub const ANSI_ITALIC: &str = "\x1b[3m";
+pub const ANSI_BOLD: &str = "\x1b[1m";
+pub const ANSI_RESET: &str = "\x1b[0m";
+
+// a binary may only declare one global allocator, so it is set here instead of in each solution:
+// the `all` binary includes all of them. Both are opt-in, so that binaries built without them allocate as usual.
+// the counting allocator is declared next to `alloc_counter::INSTALLED`, which shares its cfg.
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
+/// Helper function that reads a text file to a string.
+///
+/// If `data/<folder>/DD.txt` does not exist, the first file in `data/<folder>/DD/` is read instead (see [`puzzle_inputs`]).
+#[must_use]
+pub fn read_file(folder: &str, day: Day) -> String {
+    let cwd = env::current_dir().unwrap();
+    let folder = cwd.join("data").join(folder);
+    let name = list_inputs(&folder, day).into_iter().next().flatten();
+    let f = fs::read_to_string(input_path(&folder, day, name.as_deref()));
+    f.expect("could not open input file")
+}
+
+/// Names of the puzzle inputs stored for a day. Next to the default input `data/inputs/DD.txt` (listed first as [`None`]),
+/// further inputs, e.g. one per account, can be stored as `data/inputs/DD/<name>.txt`.
+#[must_use]
+pub fn puzzle_inputs(day: Day) -> Vec<Option<String>> {
+    list_inputs(&PathBuf::from("data").join("inputs"), day)
+}
+
+/// Path of a puzzle input returned by [`puzzle_inputs`].
+#[must_use]
+pub fn puzzle_input_path(day: Day, name: Option<&str>) -> PathBuf {
+    input_path(&PathBuf::from("data").join("inputs"), day, name)
+}
+
+fn input_path(folder: &Path, day: Day, name: Option<&str>) -> PathBuf {
+    match name {
+        None => folder.join(format!("{day}.txt")),
+        Some(name) => folder.join(day.to_string()).join(format!("{name}.txt")),
+    }
+}
+
+fn list_inputs(folder: &Path, day: Day) -> Vec<Option<String>> {
+    let mut names: Vec<String> = fs::read_dir(folder.join(day.to_string()))
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "txt" {
+                return None;
+            }
+            Some(path.file_stem()?.to_str()?.to_string())
+        })
+        .collect();
+    names.sort_unstable();
+
+    let default = input_path(folder, day, None).exists().then_some(None);
+    default
+        .into_iter()
+        .chain(names.into_iter().map(Some))
+        .collect()
+}
+
+/// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
+#[must_use]
+pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+    let cwd = env::current_dir().unwrap();
+    let filepath = cwd
+        .join("data")
+        .join(folder)
+        .join(format!("{day}-{part}.txt"));
+    let f = fs::read_to_string(filepath);
+    f.expect("could not open input file")
+}
+
+/// Creates the constant `DAY` and sets up the input and runner for each part.
+///
+/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// At runtime, `--part <part>` does the same and `--example` / `--input <path>` select another input
+/// (see [`runner::InputSource`]).
+///
+/// Passing `parse = <fn>` runs the given function on the input once and hands a reference to its
+/// result to both parts, e.g. `solution!(5, parse = parse)` with `fn parse(input: &str) -> Manual`
+/// and `fn part_one(manual: &Manual) -> Option<u32>`. Parse time is reported separately.
+///
+/// Besides `main`, the macro exports the solution as `SOLUTION`, which the `all` binary uses to run every
+/// day in a single process (see [`registry`]).
+#[macro_export]
+macro_rules! solution {
+    ($day:expr) => {
+        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    };
+    ($day:expr, 1) => {
+        $crate::solution!(@impl $day, [part_one, 1]);
+    };
+    ($day:expr, 2) => {
+        $crate::solution!(@impl $day, [part_two, 2]);
+    };
+    ($day:expr, parse = $parse:expr) => {
+        $crate::solution!(@impl_parsed $day, $parse, [part_one, 1] [part_two, 2]);
+    };
+    ($day:expr, parse = $parse:expr, 1) => {
+        $crate::solution!(@impl_parsed $day, $parse, [part_one, 1]);
+    };
+    ($day:expr, parse = $parse:expr, 2) => {
+        $crate::solution!(@impl_parsed $day, $parse, [part_two, 2]);
+    };
+
+    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@header $day, || {
+            use $crate::template::runner::*;
+            $(
+                if runs_part($part) {
+                    run_part($func, read_input(DAY, $part).as_str(), DAY, $part);
+                }
+            )*
+        });
+    };
+
+    (@impl_parsed $day:expr, $parse:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@header $day, || {
+            use $crate::template::runner::*;
+            // parts share the parsed input unless they read different example files.
+            let mut parsed = None;
+            $(
+                if runs_part($part) {
+                    let input = read_input(DAY, $part);
+                    if !matches!(&parsed, Some((prev, _)) if *prev == input) {
+                        let result = run_parse($parse, &input);
+                        parsed = Some((input, result));
+                    }
+                    if let Some((_, Some(parsed))) = &parsed {
+                        run_part($func, parsed, DAY, $part);
+                    }
+                }
+            )*
+        });
+    };
+
+    (@header $day:expr, $run:expr) => {
+        /// The current day.
+        const DAY: $crate::template::Day = $crate::day!($day);
+
+        /// The solution as run by the `all` binary.
+        #[allow(dead_code)]
+        pub const SOLUTION: $crate::template::registry::Solution =
+            $crate::template::registry::Solution { day: DAY, run: $run };
+
+        fn main() {
+            (SOLUTION.run)();
+        }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{input_path, list_inputs};
+    use crate::day;
+    use std::{env, fs, path::PathBuf, process};
+
+    #[test]
+    fn lists_default_input_first() {
+        let folder = env::temp_dir().join(format!("aoc-inputs-{}", process::id()));
+        fs::create_dir_all(folder.join("05")).unwrap();
+        for path in ["05.txt", "05/bob.txt", "05/alice.txt", "05/notes.md"] {
+            fs::write(folder.join(path), "").unwrap();
+        }
+
+        let inputs = list_inputs(&folder, day!(5));
+        let missing = list_inputs(&folder, day!(6));
+        let _ = fs::remove_dir_all(&folder);
+
+        assert_eq!(
+            inputs,
+            vec![None, Some("alice".to_string()), Some("bob".to_string())]
+        );
+        assert_eq!(missing, vec![]);
+    }
+
+    #[test]
+    fn resolves_input_paths() {
+        let folder = PathBuf::from("data/inputs");
+        assert_eq!(
+            input_path(&folder, day!(5), None),
+            PathBuf::from("data/inputs/05.txt")
+        );
+        assert_eq!(
+            input_path(&folder, day!(5), Some("alice")),
+            PathBuf::from("data/inputs/05/alice.txt")
+        );
+    }
+}
@@ -0,0 +1,296 @@
+/// Module that updates the readme me with timing information.
+/// The approach taken is similar to how `aoc-readme-stars` handles this.
+use std::{fs, io, path::Path};
+
+use crate::template::alloc_counter::format_bytes;
+use crate::template::charts;
+use crate::template::timings::Timings;
+use crate::template::Day;
+
+static MARKER: &str = "<!--- benchmarking table --->";
+
+#[allow(dead_code)]
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+pub struct TablePosition {
+    pub pos_start: usize,
+    pub pos_end: usize,
+}
+
+#[must_use]
+pub fn get_path_for_bin(day: Day) -> String {
+    format!("./src/bin/{day}.rs")
+}
+
+/// Locates a table enclosed by a pair of `marker` comments.
+pub fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
+
+    if matches.len() > 2 {
+        return Err(Error::Parser(
+            "{}: too many occurences of marker in README.".into(),
+        ));
+    }
+
+    let pos_start = matches
+        .first()
+        .map(|m| m.0)
+        .ok_or_else(|| Error::Parser("Could not find table start position.".into()))?;
+
+    let pos_end = matches
+        .last()
+        .map(|m| m.0 + m.1.len())
+        .ok_or_else(|| Error::Parser("Could not find table end position.".into()))?;
+
+    Ok(TablePosition { pos_start, pos_end })
+}
+
+/// Builds the benchmarks table with its markers, headed by `prefix` (e.g. `##`).
+/// `charts` are markdown images shown below the total.
+pub fn construct_table(
+    prefix: &str,
+    timings: Timings,
+    total_millis: f64,
+    charts: &[String],
+) -> String {
+    let header = format!("{prefix} Benchmarks");
+
+    // the memory column is only shown once allocations were counted for any day.
+    let with_allocs = timings.data.iter().any(|t| !t.allocs.is_empty());
+
+    let mut lines: Vec<String> = vec![MARKER.into(), header, String::new()];
+
+    if with_allocs {
+        lines.push("| Day | Parse | Part 1 | Part 2 | Peak memory |".into());
+        lines.push("| :---: | :---: | :---: | :---: | :---:  |".into());
+    } else {
+        lines.push("| Day | Parse | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---: | :---:  |".into());
+    }
+
+    for timing in timings.data {
+        let path = get_path_for_bin(timing.day);
+        // a part that panicked or was stopped by a resource limit shows why instead of a time.
+        let missing = timing.status.as_ref().map_or("-", |s| s.describe());
+        let mut line = format!(
+            "| [Day {}]({}) | `{}` | `{}` | `{}` |",
+            timing.day.into_inner(),
+            path,
+            timing.parse.unwrap_or_else(|| "-".into()),
+            timing.part_1.unwrap_or_else(|| missing.into()),
+            timing.part_2.unwrap_or_else(|| missing.into())
+        );
+
+        if with_allocs {
+            let peak = timing
+                .allocs
+                .iter()
+                .map(|(_, allocs)| allocs.peak_bytes)
+                .max()
+                .map_or_else(|| "-".into(), format_bytes);
+            line.push_str(&format!(" `{peak}` |"));
+        }
+
+        lines.push(line);
+    }
+
+    lines.push(String::new());
+    lines.push(format!("**Total: {total_millis:.2}ms**"));
+
+    for chart in charts {
+        lines.push(String::new());
+        lines.push(chart.clone());
+    }
+
+    lines.push(MARKER.into());
+
+    lines.join("\n")
+}
+
+fn update_content(
+    s: &mut String,
+    timings: Timings,
+    total_millis: f64,
+    charts: &[String],
+) -> Result<(), Error> {
+    let positions = locate_table(s, MARKER)?;
+    let table = construct_table("##", timings, total_millis, charts);
+    s.replace_range(positions.pos_start..positions.pos_end, &table);
+    Ok(())
+}
+
+pub fn update(timings: Timings) -> Result<(), Error> {
+    let path = "README.md";
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    let total_millis = timings.total_millis();
+    // only draw the charts for a readme that has a place for them.
+    locate_table(&readme, MARKER)?;
+    let charts = charts::write_all(&timings, Path::new(charts::CHARTS_DIR))?;
+    update_content(&mut readme, timings, total_millis, &charts)?;
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{update_content, MARKER};
+    use crate::{
+        day,
+        template::alloc_counter::AllocStats,
+        template::report::Step,
+        template::timings::{Timing, Timings},
+    };
+
+    fn get_mock_timings() -> Timings {
+        Timings {
+            history: vec![],
+            data: vec![
+                Timing {
+                    day: day!(1),
+                    parse: None,
+                    part_1: Some("10ms".into()),
+                    part_2: Some("20ms".into()),
+                    total_nanos: 3e+10,
+                    status: None,
+                    allocs: vec![],
+                    nanos: vec![],
+                },
+                Timing {
+                    day: day!(2),
+                    parse: Some("5ms".into()),
+                    part_1: Some("30ms".into()),
+                    part_2: Some("40ms".into()),
+                    total_nanos: 7e+10,
+                    status: None,
+                    allocs: vec![],
+                    nanos: vec![],
+                },
+                Timing {
+                    day: day!(4),
+                    parse: None,
+                    part_1: Some("40ms".into()),
+                    part_2: Some("50ms".into()),
+                    total_nanos: 9e+10,
+                    status: None,
+                    allocs: vec![],
+                    nanos: vec![],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_marker_not_present() {
+        let mut s = "# readme".to_string();
+        update_content(&mut s, get_mock_timings(), 190.0, &[]).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_too_many_markers_present() {
+        let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
+        update_content(&mut s, get_mock_timings(), 190.0, &[]).unwrap();
+    }
+
+    #[test]
+    fn updates_empty_benchmarks() {
+        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, get_mock_timings(), 190.0, &[]).unwrap();
+        assert_eq!(s.contains("## Benchmarks"), true);
+    }
+
+    #[test]
+    fn updates_existing_benchmarks() {
+        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, get_mock_timings(), 190.0, &[]).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, &[]).unwrap();
+        assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
+        assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
+    }
+
+    #[test]
+    fn format_benchmarks_with_allocs() {
+        let mut timings = get_mock_timings();
+        timings.data[1].allocs = vec![
+            (
+                Step::Parse,
+                AllocStats {
+                    allocations: 2,
+                    bytes: 4096,
+                    peak_bytes: 2048,
+                },
+            ),
+            (
+                Step::Part(1),
+                AllocStats {
+                    allocations: 1,
+                    bytes: 512,
+                    peak_bytes: 512,
+                },
+            ),
+        ];
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0, &[]).unwrap();
+        assert_eq!(
+            s.contains("| Day | Parse | Part 1 | Part 2 | Peak memory |"),
+            true
+        );
+        assert_eq!(
+            s.contains("| [Day 1](./src/bin/01.rs) | `-` | `10ms` | `20ms` | `-` |"),
+            true
+        );
+        assert_eq!(
+            s.contains("| [Day 2](./src/bin/02.rs) | `5ms` | `30ms` | `40ms` | `2.0 KiB` |"),
+            true
+        );
+    }
+
+    #[test]
+    fn format_benchmarks() {
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, get_mock_timings(), 190.0, &[]).unwrap();
+        let expected = [
+            "foo",
+            "bar",
+            "<!--- benchmarking table --->",
+            "## Benchmarks",
+            "",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/01.rs) | `-` | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/02.rs) | `5ms` | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/04.rs) | `-` | `40ms` | `50ms` |",
+            "",
+            "**Total: 190.00ms**",
+            "<!--- benchmarking table --->",
+            "baz",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn embeds_charts_below_total() {
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        let charts = ["![Runtime per day](./data/charts/runtimes.svg)".to_string()];
+        update_content(&mut s, get_mock_timings(), 190.0, &charts).unwrap();
+        assert_eq!(
+            s.ends_with(
+                "**Total: 190.00ms**\n\n![Runtime per day](./data/charts/runtimes.svg)\n<!--- benchmarking table --->"
+            ),
+            true
+        );
+    }
+}
@@ -0,0 +1,107 @@
+/// Registry of the solutions compiled into the `all` binary.
+///
+/// The `solution!` macro exports every solution as a [`Solution`]. The build script of every year, `build_registry.rs`,
+/// includes each `src/bin/<day>.rs` as a module of `src/bin/all.rs` and collects these into a single list, so that
+/// `run_multi` only needs to build one binary.
+///
+/// `run_multi` still starts that binary once per day, e.g. `all 05`, even without limits: the time and memory limits
+/// are enforced on a process, and a day that panics outside its parts, aborts or is killed must not take the days
+/// after it down with it.
+use std::{env, process};
+
+use crate::template::{Day, ANSI_BOLD, ANSI_RESET};
+
+/// A solution registered for a day.
+pub struct Solution {
+    pub day: Day,
+    /// Runs the parts selected on the command line, same as the `main` of the day's own binary.
+    pub run: fn(),
+}
+
+/// Returns the solution registered for a day.
+pub fn find(solutions: &[Solution], day: Day) -> Option<&Solution> {
+    solutions.iter().find(|solution| solution.day == day)
+}
+
+/// Entry point of the `all` binary.
+///
+/// Runs the days passed as leading arguments, e.g. `all 03 05 --time`, or every registered day if none are given.
+/// All other flags are read by the runner just like for a single solution.
+pub fn run(solutions: &[Solution]) {
+    let days = parse_days(env::args().skip(1));
+
+    let to_run: Vec<&Solution> = if days.is_empty() {
+        solutions.iter().collect()
+    } else {
+        days.iter()
+            .map(|day| {
+                find(solutions, *day).unwrap_or_else(|| {
+                    eprintln!("No solution registered for day {day}.");
+                    process::exit(1);
+                })
+            })
+            .collect()
+    };
+
+    // a single day is run on behalf of `run_multi`, which prints the header itself.
+    let print_headers = to_run.len() > 1;
+
+    for (i, solution) in to_run.into_iter().enumerate() {
+        if print_headers {
+            if i > 0 {
+                println!();
+            }
+            println!("{ANSI_BOLD}Day {}{ANSI_RESET}", solution.day);
+            println!("------");
+        }
+
+        (solution.run)();
+    }
+}
+
+/// Reads days from the leading arguments, stopping at the first one that is not a day.
+fn parse_days(args: impl Iterator<Item = String>) -> Vec<Day> {
+    args.map_while(|arg| arg.parse().ok()).collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{find, parse_days, Solution};
+    use crate::day;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        args.iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    #[test]
+    fn parses_leading_days() {
+        assert_eq!(
+            parse_days(args(&["03", "5", "--time", "--samples", "10"])),
+            vec![day!(3), day!(5)]
+        );
+        assert_eq!(parse_days(args(&["--budget", "10"])).is_empty(), true);
+        assert_eq!(parse_days(args(&[])).is_empty(), true);
+    }
+
+    #[test]
+    fn finds_registered_days() {
+        let solutions = [
+            Solution {
+                day: day!(1),
+                run: || {},
+            },
+            Solution {
+                day: day!(4),
+                run: || {},
+            },
+        ];
+
+        assert_eq!(find(&solutions, day!(4)).map(|s| s.day), Some(day!(4)));
+        assert_eq!(find(&solutions, day!(2)).is_none(), true);
+    }
+}
@@ -0,0 +1,362 @@
+/// Machine-readable records that solution binaries emit for `run_multi`.
+///
+/// When the environment variable [`REPORT_PATH_ENV`] is set, the runner appends one JSON object
+/// per line to that file for the parse step and every part it runs. This keeps the protocol
+/// independent from whatever the solution (or the runner) prints to stdout.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+    time::Duration,
+};
+use tinyjson::JsonValue;
+
+use crate::template::alloc_counter::AllocStats;
+
+pub const REPORT_PATH_ENV: &str = "AOC_REPORT_PATH";
+
+/// The step of a solution a [`Record`] describes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Step {
+    Parse,
+    Part(u8),
+}
+
+impl Step {
+    /// Names the step for humans, e.g. `Part 1`.
+    pub fn label(self) -> String {
+        match self {
+            Step::Parse => "Parse".into(),
+            Step::Part(part) => format!("Part {part}"),
+        }
+    }
+}
+
+/// Outcome of running a step.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    /// The part returned `None`.
+    NoResult,
+    /// The part returned an error, see [`Record::message`] for its text.
+    Error,
+    /// The part panicked, see [`Record::message`] for details.
+    Panicked,
+    /// The solution was killed after exceeding its time limit.
+    TimedOut,
+    /// The solution exceeded its memory limit.
+    OutOfMemory,
+}
+
+impl Status {
+    /// Describes an unsuccessful status for humans.
+    pub fn describe(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::NoResult => "no result",
+            Status::Error => "error",
+            Status::Panicked => "panicked",
+            Status::TimedOut => "timed out",
+            Status::OutOfMemory => "out of memory",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+    pub step: Step,
+    pub answer: Option<String>,
+    pub duration_nanos: u128,
+    pub samples: usize,
+    /// Allocations of the step, only counted with `--alloc`.
+    pub allocs: Option<AllocStats>,
+    pub status: Status,
+    /// Whether `answer` matched the known answer, [`None`] if there is none.
+    pub verified: Option<bool>,
+    /// Explains an unsuccessful status, e.g. the error text or the panic message and location.
+    pub message: Option<String>,
+}
+
+/// Formats a runtime in nanoseconds for humans, e.g. `74.1ms`.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn format_nanos(nanos: f64) -> String {
+    format!("{:.1?}", Duration::from_nanos(nanos as u64))
+}
+
+/// Appends a record to the report file if the parent process asked for one.
+pub fn emit(record: &Record) {
+    let Ok(path) = std::env::var(REPORT_PATH_ENV) else {
+        return;
+    };
+
+    let line = JsonValue::from(record).stringify();
+
+    let written = line
+        .map_err(|e| io::Error::other(e.to_string()))
+        .and_then(|line| {
+            let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+            writeln!(file, "{line}")
+        });
+
+    if let Err(e) = written {
+        eprintln!("Failed to write report to \"{path}\": {e}");
+    }
+}
+
+/// Reads all records from a report file. A missing file yields no records.
+pub fn read(path: &Path) -> Result<Vec<Record>, String> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e.to_string()),
+    };
+
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let json = JsonValue::from_str(line).or(Err("report line is not valid JSON."))?;
+            Record::try_from(&json)
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl Display for Step {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Step::Parse => write!(f, "parse"),
+            Step::Part(part) => write!(f, "{part}"),
+        }
+    }
+}
+
+impl FromStr for Step {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Step::Parse),
+            s => s
+                .parse()
+                .map(Step::Part)
+                .map_err(|_| format!("unknown step `{s}`.")),
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::NoResult => write!(f, "none"),
+            Status::Error => write!(f, "error"),
+            Status::Panicked => write!(f, "panic"),
+            Status::TimedOut => write!(f, "timeout"),
+            Status::OutOfMemory => write!(f, "oom"),
+        }
+    }
+}
+
+impl FromStr for Status {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ok" => Ok(Status::Ok),
+            "none" => Ok(Status::NoResult),
+            "error" => Ok(Status::Error),
+            "panic" => Ok(Status::Panicked),
+            "timeout" => Ok(Status::TimedOut),
+            "oom" => Ok(Status::OutOfMemory),
+            s => Err(format!("unknown status `{s}`.")),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Record> for JsonValue {
+    fn from(value: &Record) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("step".into(), JsonValue::String(value.step.to_string()));
+        map.insert("status".into(), JsonValue::String(value.status.to_string()));
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert(
+            "duration_nanos".into(),
+            JsonValue::Number(value.duration_nanos as f64),
+        );
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert(
+            "allocs".into(),
+            match &value.allocs {
+                Some(x) => JsonValue::from(x),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert(
+            "message".into(),
+            match &value.message {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert(
+            "verified".into(),
+            match value.verified {
+                Some(x) => JsonValue::Boolean(x),
+                None => JsonValue::Null,
+            },
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Record {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected record to be a JSON object.")?;
+
+        let step = json
+            .get("step")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected record.step to be a string.")?
+            .parse()?;
+
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected record.status to be a string.")?
+            .parse()?;
+
+        let answer = match json.get("answer") {
+            Some(JsonValue::Null) => None,
+            Some(JsonValue::String(answer)) => Some(answer.clone()),
+            _ => return Err("Expected record.answer to be null or string.".into()),
+        };
+
+        let duration_nanos = json
+            .get("duration_nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected record.duration_nanos to be a number.")?;
+
+        let samples = json
+            .get("samples")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected record.samples to be a number.")?;
+
+        let allocs = match json.get("allocs") {
+            None | Some(JsonValue::Null) => None,
+            Some(v) => Some(AllocStats::try_from(v)?),
+        };
+
+        let verified = json.get("verified").and_then(|v| v.get::<bool>().copied());
+        let message = json.get("message").and_then(|v| v.get::<String>()).cloned();
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(Record {
+            step,
+            answer,
+            duration_nanos: duration_nanos as u128,
+            samples: samples as usize,
+            allocs,
+            status,
+            verified,
+            message,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Record, Status, Step};
+    use crate::template::alloc_counter::AllocStats;
+    use std::str::FromStr;
+    use tinyjson::JsonValue;
+
+    #[test]
+    fn round_trips_records() {
+        let record = Record {
+            step: Step::Part(2),
+            answer: Some("line 1\nline 2 (1ms @ 2 samples)".into()),
+            duration_nanos: 74_130,
+            samples: 100,
+            allocs: Some(AllocStats {
+                allocations: 12,
+                bytes: 4096,
+                peak_bytes: 1024,
+            }),
+            status: Status::Ok,
+            verified: Some(false),
+            message: None,
+        };
+
+        let line = JsonValue::from(&record).stringify().unwrap();
+        assert_eq!(line.contains('\n'), false);
+
+        let parsed = Record::try_from(&JsonValue::from_str(&line).unwrap()).unwrap();
+        assert_eq!(parsed, record);
+    }
+
+    #[test]
+    fn parses_missing_answers() {
+        let json = r#"{ "step": "1", "status": "none", "answer": null, "duration_nanos": 10, "samples": 1 }"#;
+        let record = Record::try_from(&JsonValue::from_str(json).unwrap()).unwrap();
+        assert_eq!(record.step, Step::Part(1));
+        assert_eq!(record.status, Status::NoResult);
+        assert_eq!(record.answer, None);
+        assert_eq!(record.verified, None);
+        assert_eq!(record.allocs, None);
+    }
+
+    #[test]
+    fn parses_parse_steps() {
+        let json = r#"{ "step": "parse", "status": "ok", "answer": null, "duration_nanos": 10, "samples": 5 }"#;
+        let record = Record::try_from(&JsonValue::from_str(json).unwrap()).unwrap();
+        assert_eq!(record.step, Step::Parse);
+        assert_eq!(record.samples, 5);
+    }
+
+    #[test]
+    fn parses_panics() {
+        let json = r#"{ "step": "2", "status": "panic", "answer": null, "duration_nanos": 0, "samples": 0, "message": "panicked at src/bin/01.rs:4:5: oops" }"#;
+        let record = Record::try_from(&JsonValue::from_str(json).unwrap()).unwrap();
+        assert_eq!(record.status, Status::Panicked);
+        assert_eq!(
+            record.message,
+            Some("panicked at src/bin/01.rs:4:5: oops".into())
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_numeric_answers() {
+        let json =
+            r#"{ "step": "1", "status": "ok", "answer": 143, "duration_nanos": 10, "samples": 1 }"#;
+        Record::try_from(&JsonValue::from_str(json).unwrap()).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_unknown_status() {
+        let json = r#"{ "step": "1", "status": "maybe", "answer": null, "duration_nanos": 10, "samples": 1 }"#;
+        Record::try_from(&JsonValue::from_str(json).unwrap()).unwrap();
+    }
+}
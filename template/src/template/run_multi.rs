@@ -0,0 +1,917 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::{Display, Write},
+    io,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+use crate::template::{
+    limits::Limits,
+    puzzle_inputs,
+    report::{Record, Status, Step},
+    runner::{BenchConfig, InputSource},
+    Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+
+use super::{
+    all_days,
+    timings::{Timing, Timings},
+};
+
+/// A day run on one of its puzzle inputs, [`None`] standing for the default input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayInput {
+    pub day: Day,
+    pub input: Option<String>,
+}
+
+impl Display for DayInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.input {
+            Some(name) => write!(f, "Day {} ({name})", self.day),
+            None => write!(f, "Day {}", self.day),
+        }
+    }
+}
+
+/// Outcome of running a set of days.
+#[derive(Debug, Default)]
+pub struct MultiRun {
+    /// Benchmark results, only present for timed runs.
+    pub timings: Option<Timings>,
+    /// Parts whose result matched their known answer.
+    pub passed: usize,
+    /// Parts whose result did not match their known answer.
+    pub failed: Vec<(DayInput, u8)>,
+    /// Parts without a known answer.
+    pub unverified: usize,
+    /// Steps that errored, panicked or were stopped by a limit, with an explanation.
+    pub errors: Vec<(DayInput, Step, String)>,
+}
+
+impl MultiRun {
+    fn add_records(&mut self, records: &[Record], target: &DayInput) {
+        for record in records {
+            if !matches!(record.status, Status::Ok | Status::NoResult) {
+                let message = record
+                    .message
+                    .clone()
+                    .unwrap_or_else(|| record.status.describe().into());
+                self.errors.push((target.clone(), record.step, message));
+            }
+
+            let Step::Part(part) = record.step else {
+                continue;
+            };
+
+            match record.verified {
+                Some(true) => self.passed += 1,
+                Some(false) => self.failed.push((target.clone(), part)),
+                None => self.unverified += 1,
+            }
+        }
+    }
+}
+
+/// Runs a set of days, up to `jobs` of them at the same time.
+///
+/// With more than one job, the output of each day is buffered and printed in order once the day completed.
+pub fn run_multi(
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    bench: Option<&BenchConfig>,
+    limits: &Limits,
+    jobs: usize,
+) -> MultiRun {
+    let mut run = MultiRun::default();
+    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+
+    // every day runs from the `all` binary, so only a single binary needs to be built.
+    // each day is still a process of its own, see `registry` for why.
+    let count_allocs = bench.is_some_and(|bench| bench.alloc);
+    let registry = child_commands::build_registry(is_release, count_allocs).unwrap_or_else(|e| {
+        eprintln!("Failed to run cargo: {e:?}");
+        None
+    });
+    if registry.is_none() {
+        eprintln!(
+            "Failed to build the `all` binary, running each day from its own binary instead."
+        );
+    }
+
+    // NOTE: use non-duplicate, sorted day values.
+    let days: Vec<Day> = all_days().filter(|day| days_to_run.contains(day)).collect();
+
+    let run_day = |day: Day, output: &mut Output| {
+        run_day(day, is_release, bench, limits, registry.as_deref(), output)
+    };
+
+    let mut add_day = |results: Vec<(DayInput, Vec<Record>)>| {
+        for (i, (target, records)) in results.into_iter().enumerate() {
+            // only the default input is benched.
+            if i == 0 {
+                timings.push(child_commands::collect_timing(&records, target.day));
+            }
+            run.add_records(&records, &target);
+        }
+    };
+
+    if jobs <= 1 {
+        for (i, day) in days.iter().enumerate() {
+            if i > 0 {
+                println!();
+            }
+            add_day(run_day(*day, &mut Output::Stdout));
+        }
+    } else {
+        let next = AtomicUsize::new(0);
+
+        thread::scope(|scope| {
+            let (tx, rx) = mpsc::channel();
+
+            for _ in 0..jobs.min(days.len()) {
+                let (tx, next, days, run_day) = (tx.clone(), &next, &days, &run_day);
+                scope.spawn(move || {
+                    while let Some(day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                        let mut output = Output::Buffer(String::new());
+                        let results = run_day(*day, &mut output);
+                        if tx.send((*day, output, results)).is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+            drop(tx);
+
+            // print days in order as soon as all days before them completed.
+            let mut pending = BTreeMap::new();
+            let mut to_print = days.iter().peekable();
+
+            for (day, output, results) in rx {
+                pending.insert(day, (output, results));
+
+                while let Some((output, results)) =
+                    to_print.peek().and_then(|day| pending.remove(*day))
+                {
+                    if to_print.next() != days.first() {
+                        println!();
+                    }
+                    if let Output::Buffer(buffer) = output {
+                        print!("{buffer}");
+                    }
+                    add_day(results);
+                }
+            }
+        });
+    }
+
+    if bench.is_some() {
+        let timings = Timings {
+            data: timings,
+            history: vec![],
+        };
+        let total_millis = timings.total_millis();
+        println!(
+            "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+        );
+        run.timings = Some(timings);
+    }
+
+    run
+}
+
+/// Runs a day on its puzzle inputs and returns the records reported for each of them.
+/// Returns no records if the day has not been scaffolded yet.
+fn run_day(
+    day: Day,
+    is_release: bool,
+    bench: Option<&BenchConfig>,
+    limits: &Limits,
+    registry: Option<&str>,
+    output: &mut Output,
+) -> Vec<(DayInput, Vec<Record>)> {
+    let mut results = vec![];
+
+    output.println(format!("{ANSI_BOLD}Day {day}{ANSI_RESET}"));
+    output.println("------");
+
+    // every puzzle input is verified, but only the default one is benched.
+    let inputs = match puzzle_inputs(day) {
+        inputs if bench.is_none() && inputs.len() > 1 => inputs,
+        _ => vec![None],
+    };
+
+    // mirror `--time` flag and bench settings to child invocations.
+    let bench_args = bench.map(BenchConfig::to_args).unwrap_or_default();
+
+    for input in inputs {
+        if let Some(name) = &input {
+            output.println(format!("{ANSI_ITALIC}Input {name}{ANSI_RESET}"));
+        }
+
+        let source = InputSource::Puzzle(input.clone());
+        let args = [bench_args.clone(), source.to_args()].concat();
+
+        let records =
+            match child_commands::run_solution(day, &args, is_release, limits, registry, output) {
+                Ok(Some(records)) => records,
+                Ok(None) => {
+                    output.println("Not solved.");
+                    break;
+                }
+                Err(e) => {
+                    let record = child_commands::failed_record(&e);
+                    output.println(format!(
+                        "✖ {}",
+                        record.message.as_deref().unwrap_or_default()
+                    ));
+                    vec![record]
+                }
+            };
+
+        results.push((DayInput { day, input }, records));
+    }
+
+    results
+}
+
+/// Where the output of a day goes. Days that run in parallel are buffered, so their output does not interleave.
+pub enum Output {
+    Stdout,
+    Buffer(String),
+}
+
+impl Output {
+    fn println(&mut self, line: impl Display) {
+        match self {
+            Output::Stdout => println!("{line}"),
+            Output::Buffer(buffer) => {
+                let _ = writeln!(buffer, "{line}");
+            }
+        }
+    }
+}
+
+/// Runs the solution of a day once per puzzle input and returns the records reported for each of them.
+/// Returns [`None`] if the day has not been scaffolded yet.
+pub fn run_inputs(
+    day: Day,
+    is_release: bool,
+    part: Option<u8>,
+    limits: &Limits,
+) -> Option<Vec<(Option<String>, Vec<Record>)>> {
+    let mut results = vec![];
+
+    for input in puzzle_inputs(day) {
+        println!(
+            "{ANSI_BOLD}Input {}{ANSI_RESET}",
+            input.as_deref().unwrap_or("default")
+        );
+
+        let mut args = InputSource::Puzzle(input.clone()).to_args();
+        if let Some(part) = part {
+            args.extend(["--part".into(), part.to_string()]);
+        }
+
+        let records = match child_commands::run_solution(
+            day,
+            &args,
+            is_release,
+            limits,
+            None,
+            &mut Output::Stdout,
+        ) {
+            Ok(records) => records?,
+            Err(e) => {
+                let record = child_commands::failed_record(&e);
+                eprintln!("{}", record.message.as_deref().unwrap_or_default());
+                vec![record]
+            }
+        };
+        results.push((input, records));
+        println!();
+    }
+
+    Some(results)
+}
+
+#[allow(dead_code)]
+#[derive(Debug)]
+pub enum Error {
+    BrokenPipe,
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+#[must_use]
+pub fn get_path_for_bin(day: Day) -> String {
+    format!("./src/bin/{day}.rs")
+}
+
+/// All solutions live in isolated binaries, and are additionally compiled into the `all` binary (see [`crate::template::registry`]).
+/// This module encapsulates interaction with these binaries, both invoking them as well as collecting the records they report.
+pub mod child_commands {
+    use super::{get_path_for_bin, Error, Output};
+    use crate::template::{
+        limits::Limits,
+        report::{self, Record, Status, Step, REPORT_PATH_ENV},
+        Day,
+    };
+    use std::{
+        collections::HashMap,
+        env, fs,
+        io::Read,
+        path::Path,
+        process::{self, Command, Stdio},
+        str::FromStr,
+        thread,
+        time::Duration,
+    };
+    use tinyjson::JsonValue;
+
+    /// Run the solution for a given day with `args`. Returns [`None`] if the day has not been scaffolded yet.
+    ///
+    /// The day runs from the `registry` executable built by [`build_registry`] if there is one, otherwise its own bin is built first.
+    /// Either way the executable runs directly instead of through cargo, so that `limits` only apply to the solution itself.
+    pub fn run_solution(
+        day: Day,
+        args: &[String],
+        is_release: bool,
+        limits: &Limits,
+        registry: Option<&str>,
+        output: &mut Output,
+    ) -> Result<Option<Vec<Record>>, Error> {
+        // skip command invocation for days that have not been scaffolded yet.
+        if !Path::new(&get_path_for_bin(day)).exists() {
+            return Ok(None);
+        }
+
+        let (executable, args) = match registry {
+            Some(registry) => (
+                registry.to_string(),
+                [vec![day.to_string()], args.to_vec()].concat(),
+            ),
+            None => {
+                let count_allocs = args.iter().any(|arg| arg == "--alloc");
+                let Some(executable) = build_bin(&day.to_string(), is_release, count_allocs)?
+                else {
+                    eprintln!("Failed to build solution for day {day}.");
+                    return Ok(Some(vec![]));
+                };
+                (executable, args.to_vec())
+            }
+        };
+
+        run_executable(&executable, &args, day, limits, output).map(Some)
+    }
+
+    /// Runs an executable of the solution for a given day and collects the records it reports.
+    pub fn run_executable(
+        executable: &str,
+        args: &[String],
+        day: Day,
+        limits: &Limits,
+        output: &mut Output,
+    ) -> Result<Vec<Record>, Error> {
+        // the child appends its records to this file while its output goes straight to the terminal.
+        let report_path = env::temp_dir().join(format!("aoc-report-{}-{day}.jsonl", process::id()));
+        let _ = fs::remove_file(&report_path);
+
+        let mut cmd = Command::new(executable);
+        cmd.args(args)
+            .env(REPORT_PATH_ENV, &report_path)
+            .stdout(match output {
+                Output::Stdout => Stdio::inherit(),
+                Output::Buffer(_) => Stdio::piped(),
+            })
+            .stderr(Stdio::inherit());
+        limits.apply(&mut cmd);
+
+        let mut child = cmd.spawn()?;
+
+        // read the output while waiting, a child that fills the pipe would block otherwise.
+        let stdout = child.stdout.take().map(|mut stdout| {
+            thread::spawn(move || {
+                let mut buffer = vec![];
+                let _ = stdout.read_to_end(&mut buffer);
+                buffer
+            })
+        });
+
+        let interrupted = limits.wait(&mut child)?;
+
+        if let (Some(stdout), Output::Buffer(buffer)) = (stdout, &mut *output) {
+            let stdout = stdout.join().unwrap_or_default();
+            buffer.push_str(&String::from_utf8_lossy(&stdout));
+        }
+
+        let mut records = report::read(&report_path).unwrap_or_else(|e| {
+            eprintln!("Could not read report for day {day}: {e}");
+            vec![]
+        });
+        let _ = fs::remove_file(&report_path);
+
+        if let Some(status) = interrupted {
+            let has_parse =
+                fs::read_to_string(get_path_for_bin(day)).is_ok_and(|s| has_parse_step(&s));
+            let record = interrupted_record(&records, status, has_parse);
+            output.println(format!(
+                "\r{}: ✖ {}",
+                record.step.label(),
+                record.status.describe()
+            ));
+            records.push(record);
+        }
+
+        Ok(records)
+    }
+
+    /// Builds the `all` binary, which runs any day in-process, and returns the path to its executable.
+    /// Returns [`None`] if it does not build, e.g. because one of the solutions does not compile.
+    pub fn build_registry(is_release: bool, count_allocs: bool) -> Result<Option<String>, Error> {
+        build_bin("all", is_release, count_allocs)
+    }
+
+    /// Builds a bin and returns the path to its executable.
+    /// With `count_allocs`, it is built with the counting allocator that `--alloc` needs.
+    pub fn build_bin(
+        bin: &str,
+        is_release: bool,
+        count_allocs: bool,
+    ) -> Result<Option<String>, Error> {
+        build_bin_with(bin, is_release, count_allocs, |_| {})
+    }
+
+    /// Builds a bin of the package in `dir`, e.g. a checkout of another revision, into `target_dir`.
+    pub fn build_bin_in(
+        bin: &str,
+        is_release: bool,
+        count_allocs: bool,
+        dir: &Path,
+        target_dir: &Path,
+    ) -> Result<Option<String>, Error> {
+        build_bin_with(bin, is_release, count_allocs, |cmd| {
+            cmd.current_dir(dir).env("CARGO_TARGET_DIR", target_dir);
+        })
+    }
+
+    fn build_bin_with(
+        bin: &str,
+        is_release: bool,
+        count_allocs: bool,
+        configure: impl FnOnce(&mut Command),
+    ) -> Result<Option<String>, Error> {
+        let mut args = vec![
+            "build",
+            "--quiet",
+            "--bin",
+            bin,
+            "--message-format=json-render-diagnostics",
+        ];
+
+        if is_release {
+            args.push("--release");
+        }
+
+        if count_allocs {
+            args.extend(["--features", "count-allocs"]);
+        }
+
+        let mut cmd = Command::new("cargo");
+        cmd.args(&args).stderr(Stdio::inherit());
+        configure(&mut cmd);
+        let output = cmd.output()?;
+
+        if !output.status.success() {
+            return Ok(None);
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .find_map(|line| parse_executable(line, bin)))
+    }
+
+    /// Extracts the executable path from a cargo `compiler-artifact` message for the given bin.
+    fn parse_executable(line: &str, bin: &str) -> Option<String> {
+        let json = JsonValue::from_str(line).ok()?;
+        let message = json.get::<HashMap<String, JsonValue>>()?;
+
+        let name = message
+            .get("target")?
+            .get::<HashMap<String, JsonValue>>()?
+            .get("name")?
+            .get::<String>()?;
+
+        if name != bin {
+            return None;
+        }
+
+        message.get("executable")?.get::<String>().cloned()
+    }
+
+    /// Whether the source of a solution declares a parser shared by its parts, i.e. `solution!(1, parse = ...)`.
+    fn has_parse_step(source: &str) -> bool {
+        source
+            .split_once("solution!(")
+            .and_then(|(_, rest)| rest.split(';').next())
+            .is_some_and(|declaration| declaration.contains("parse ="))
+    }
+
+    /// Creates the record for the step a solution was running when it was stopped by a limit.
+    /// A solution with a parser was still parsing if it reported neither the parse step nor any part.
+    fn interrupted_record(records: &[Record], status: Status, has_parse: bool) -> Record {
+        let last_part = records
+            .iter()
+            .filter_map(|r| match r.step {
+                Step::Part(part) => Some(part),
+                Step::Parse => None,
+            })
+            .max();
+        let parsed = records.iter().any(|r| r.step == Step::Parse);
+
+        let step = match last_part {
+            Some(part) => Step::Part(part + 1),
+            None if has_parse && !parsed => Step::Parse,
+            None => Step::Part(1),
+        };
+
+        Record {
+            step,
+            answer: None,
+            duration_nanos: 0,
+            samples: 0,
+            allocs: None,
+            status,
+            verified: None,
+            message: None,
+        }
+    }
+
+    /// Creates the record for a solution that could not be run at all, e.g. because its binary failed to start.
+    pub fn failed_record(error: &Error) -> Record {
+        Record {
+            message: Some(format!("failed to run the solution: {error:?}")),
+            ..interrupted_record(&[], Status::Error, false)
+        }
+    }
+
+    /// Builds the timing for a day from the records its solution reported.
+    pub fn collect_timing(records: &[Record], day: Day) -> super::Timing {
+        let mut timing = super::Timing {
+            day,
+            parse: None,
+            part_1: None,
+            part_2: None,
+            total_nanos: 0_f64,
+            status: None,
+            allocs: vec![],
+            nanos: vec![],
+        };
+
+        for record in records {
+            if let Some(allocs) = record.allocs {
+                timing.allocs.push((record.step, allocs));
+            }
+
+            if record.status != Status::Ok {
+                if record.status != Status::NoResult {
+                    timing.status = Some(record.status.clone());
+                }
+                continue;
+            }
+
+            #[allow(clippy::cast_possible_truncation)]
+            let duration = Duration::from_nanos(record.duration_nanos as u64);
+            let timing_str = Some(format!("{duration:.1?}"));
+
+            match record.step {
+                Step::Parse => timing.parse = timing_str,
+                Step::Part(1) => timing.part_1 = timing_str,
+                Step::Part(2) => timing.part_2 = timing_str,
+                Step::Part(_) => continue,
+            }
+
+            #[allow(clippy::cast_precision_loss)]
+            let nanos = record.duration_nanos as f64;
+            timing.total_nanos += nanos;
+            timing.nanos.push((record.step, nanos));
+        }
+
+        timing
+    }
+
+    #[cfg(feature = "test_lib")]
+    mod tests {
+        use super::{
+            collect_timing, failed_record, has_parse_step, interrupted_record, parse_executable,
+        };
+        use crate::template::run_multi::Error;
+
+        use crate::day;
+        use crate::template::alloc_counter::AllocStats;
+        use crate::template::report::{Record, Status, Step};
+
+        fn record(step: Step, status: Status, duration_nanos: u128) -> Record {
+            Record {
+                step,
+                answer: None,
+                duration_nanos,
+                samples: 100,
+                allocs: None,
+                status,
+                verified: None,
+                message: None,
+            }
+        }
+
+        #[test]
+        fn collects_execution_times() {
+            let res = collect_timing(
+                &[
+                    record(Step::Part(1), Status::Ok, 74),
+                    record(Step::Part(2), Status::Ok, 74_130_000),
+                ],
+                day!(1),
+            );
+            assert_eq!(res.total_nanos, 74_130_074_f64);
+            assert_eq!(res.parse.is_none(), true);
+            assert_eq!(res.part_1.unwrap(), "74.0ns");
+            assert_eq!(res.part_2.unwrap(), "74.1ms");
+        }
+
+        #[test]
+        fn collects_parse_times() {
+            let res = collect_timing(
+                &[
+                    record(Step::Parse, Status::Ok, 1_500),
+                    record(Step::Part(1), Status::Ok, 74),
+                    record(Step::Part(2), Status::Ok, 74_130_000),
+                ],
+                day!(1),
+            );
+            assert_eq!(res.total_nanos, 74_131_574_f64);
+            assert_eq!(res.parse.unwrap(), "1.5µs");
+            assert_eq!(
+                res.nanos,
+                vec![
+                    (Step::Parse, 1_500_f64),
+                    (Step::Part(1), 74_f64),
+                    (Step::Part(2), 74_130_000_f64)
+                ]
+            );
+        }
+
+        #[test]
+        fn collects_missing_parts() {
+            let res = collect_timing(
+                &[
+                    record(Step::Part(1), Status::NoResult, 10),
+                    record(Step::Part(2), Status::NoResult, 10),
+                ],
+                day!(1),
+            );
+            assert_eq!(res.total_nanos, 0_f64);
+            assert_eq!(res.part_1.is_none(), true);
+            assert_eq!(res.part_2.is_none(), true);
+        }
+
+        #[test]
+        fn collects_interrupted_runs() {
+            let res = collect_timing(
+                &[
+                    record(Step::Part(1), Status::Ok, 74),
+                    record(Step::Part(2), Status::TimedOut, 0),
+                ],
+                day!(1),
+            );
+            assert_eq!(res.part_1.unwrap(), "74.0ns");
+            assert_eq!(res.part_2.is_none(), true);
+            assert_eq!(res.status, Some(Status::TimedOut));
+        }
+
+        #[test]
+        fn collects_panicked_parts() {
+            let res = collect_timing(
+                &[
+                    record(Step::Part(1), Status::Panicked, 0),
+                    record(Step::Part(2), Status::NoResult, 10),
+                ],
+                day!(1),
+            );
+            assert_eq!(res.part_1.is_none(), true);
+            assert_eq!(res.status, Some(Status::Panicked));
+        }
+
+        #[test]
+        fn marks_next_part_as_interrupted() {
+            let record = interrupted_record(
+                &[
+                    record(Step::Parse, Status::Ok, 10),
+                    record(Step::Part(1), Status::Ok, 10),
+                ],
+                Status::OutOfMemory,
+                true,
+            );
+            assert_eq!(record.step, Step::Part(2));
+            assert_eq!(record.status, Status::OutOfMemory);
+            assert_eq!(
+                interrupted_record(&[], Status::TimedOut, false).step,
+                Step::Part(1)
+            );
+        }
+
+        #[test]
+        fn marks_parse_as_interrupted() {
+            assert_eq!(
+                interrupted_record(&[], Status::TimedOut, true).step,
+                Step::Parse
+            );
+            // the parse step completed, so the first part was running.
+            assert_eq!(
+                interrupted_record(
+                    &[record(Step::Parse, Status::Ok, 10)],
+                    Status::OutOfMemory,
+                    true
+                )
+                .step,
+                Step::Part(1)
+            );
+        }
+
+        #[test]
+        fn reports_solutions_that_failed_to_run() {
+            let error = Error::IO(std::io::Error::from(std::io::ErrorKind::PermissionDenied));
+            let record = failed_record(&error);
+
+            assert_eq!(record.status, Status::Error);
+            assert_eq!(record.step, Step::Part(1));
+            assert_eq!(
+                record
+                    .message
+                    .unwrap()
+                    .starts_with("failed to run the solution: IO("),
+                true
+            );
+        }
+
+        #[test]
+        fn detects_parse_steps() {
+            assert_eq!(
+                has_parse_step("advent_of_code::solution!(5, parse = parse_input);\n"),
+                true
+            );
+            assert_eq!(has_parse_step("advent_of_code::solution!(5);\n"), false);
+            assert_eq!(
+                has_parse_step("advent_of_code::solution!(5);\n\nfn f() { let parse = 1; }"),
+                false
+            );
+        }
+
+        #[test]
+        fn parses_executable_from_cargo_messages() {
+            let line = r#"{"reason":"compiler-artifact","target":{"name":"05"},"executable":"/tmp/target/release/05"}"#;
+            assert_eq!(
+                parse_executable(line, "05"),
+                Some("/tmp/target/release/05".into())
+            );
+            assert_eq!(parse_executable(line, "06"), None);
+            assert_eq!(
+                parse_executable(r#"{"reason":"build-finished"}"#, "05"),
+                None
+            );
+        }
+
+        #[test]
+        fn collects_allocs() {
+            let allocs = AllocStats {
+                allocations: 3,
+                bytes: 96,
+                peak_bytes: 64,
+            };
+            let res = collect_timing(
+                &[
+                    Record {
+                        allocs: Some(allocs),
+                        ..record(Step::Part(1), Status::Ok, 74)
+                    },
+                    record(Step::Part(2), Status::Ok, 74),
+                ],
+                day!(1),
+            );
+            assert_eq!(res.allocs, vec![(Step::Part(1), allocs)]);
+        }
+
+        #[test]
+        fn collects_empty_reports() {
+            let res = collect_timing(&[], day!(1));
+            assert_eq!(res.total_nanos, 0_f64);
+            assert_eq!(res.part_1.is_none(), true);
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{DayInput, MultiRun, Output};
+
+    use crate::template::report::{Record, Status, Step};
+
+    fn target(day: u8, input: Option<&str>) -> DayInput {
+        DayInput {
+            day: crate::template::Day::new(day).unwrap(),
+            input: input.map(Into::into),
+        }
+    }
+
+    fn record(step: Step, verified: Option<bool>) -> Record {
+        Record {
+            step,
+            answer: None,
+            duration_nanos: 0,
+            samples: 1,
+            allocs: None,
+            status: Status::Ok,
+            verified,
+            message: None,
+        }
+    }
+
+    #[test]
+    fn collects_error_messages() {
+        let mut run = MultiRun::default();
+        run.add_records(
+            &[
+                Record {
+                    status: Status::Error,
+                    message: Some("bad input".into()),
+                    ..record(Step::Part(1), None)
+                },
+                Record {
+                    status: Status::TimedOut,
+                    ..record(Step::Part(2), None)
+                },
+                Record {
+                    status: Status::NoResult,
+                    ..record(Step::Part(2), None)
+                },
+            ],
+            &target(5, None),
+        );
+
+        assert_eq!(
+            run.errors,
+            vec![
+                (target(5, None), Step::Part(1), "bad input".to_string()),
+                (target(5, None), Step::Part(2), "timed out".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn tallies_verified_parts() {
+        let mut run = MultiRun::default();
+        run.add_records(
+            &[
+                record(Step::Parse, None),
+                record(Step::Part(1), Some(true)),
+                record(Step::Part(2), Some(false)),
+            ],
+            &target(5, None),
+        );
+        run.add_records(&[record(Step::Part(1), None)], &target(6, None));
+        run.add_records(
+            &[record(Step::Part(1), Some(false))],
+            &target(6, Some("alice")),
+        );
+
+        assert_eq!(run.passed, 1);
+        assert_eq!(
+            run.failed,
+            vec![(target(5, None), 2), (target(6, Some("alice")), 1)]
+        );
+        assert_eq!(run.unverified, 1);
+    }
+
+    #[test]
+    fn buffers_output() {
+        let mut output = Output::Buffer(String::new());
+        output.println("Day 05");
+        output.println("------");
+        assert!(matches!(output, Output::Buffer(buffer) if buffer == "Day 05\n------\n"));
+    }
+
+    #[test]
+    fn describes_inputs() {
+        assert_eq!(target(5, None).to_string(), "Day 05");
+        assert_eq!(target(5, Some("alice")).to_string(), "Day 05 (alice)");
+    }
+}
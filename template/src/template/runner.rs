@@ -0,0 +1,796 @@
+/// Encapsulates code that interacts with solution functions.
+use std::cell::{Cell, RefCell};
+use std::fmt::Display;
+use std::hint::black_box;
+use std::io::{stdout, Read, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::sync::{Once, OnceLock};
+use std::time::{Duration, Instant};
+use std::{cmp, env, fs, io, process};
+
+use crate::template::alloc_counter::{self, format_bytes, AllocStats};
+use crate::template::answers::{self, Answers, Verdict};
+use crate::template::report::{self, Record, Status, Step};
+use crate::template::stats::Stats;
+use crate::template::submissions::{self, Submission};
+use crate::template::ANSI_BOLD;
+use crate::template::{aoc_client, puzzle_input_path, puzzle_inputs, Day, ANSI_ITALIC, ANSI_RESET};
+
+/// Outcome of running a solution part.
+pub enum Outcome<T> {
+    Answer(T),
+    /// The part returned `None`.
+    NoResult,
+    /// The part returned an error, holding its display text.
+    Error(String),
+}
+
+/// Return types supported for solution parts: `Option<T>` and `Result<T, E>` where `T` and `E` implement [`Display`].
+pub trait PartResult {
+    type Answer: Display;
+
+    fn into_outcome(self) -> Outcome<Self::Answer>;
+}
+
+impl<T: Display> PartResult for Option<T> {
+    type Answer = T;
+
+    fn into_outcome(self) -> Outcome<T> {
+        match self {
+            Some(answer) => Outcome::Answer(answer),
+            None => Outcome::NoResult,
+        }
+    }
+}
+
+impl<T: Display, E: Display> PartResult for Result<T, E> {
+    type Answer = T;
+
+    fn into_outcome(self) -> Outcome<T> {
+        match self {
+            Ok(answer) => Outcome::Answer(answer),
+            Err(e) => Outcome::Error(e.to_string()),
+        }
+    }
+}
+
+pub fn run_part<I: Clone, R: PartResult>(func: impl Fn(I) -> R, input: I, day: Day, part: u8) {
+    let part_str = format!("Part {part}");
+
+    let func = |input| func(input).into_outcome();
+
+    let (result, stats, allocs) = match run_timed(func, input, |result| {
+        print_result(result, &part_str, "", "");
+    }) {
+        Ok(run) => run,
+        Err(panic) => {
+            let verified = match known_answers(day).map(|a| a.verify(part, None)) {
+                None | Some(Verdict::Unknown) => None,
+                Some(Verdict::Correct | Verdict::Wrong { .. }) => Some(false),
+            };
+            report_panic(&panic, &part_str, Step::Part(part), verified);
+            return;
+        }
+    };
+
+    let answer = match &result {
+        Outcome::Answer(answer) => Some(answer.to_string()),
+        Outcome::NoResult | Outcome::Error(_) => None,
+    };
+    let input_name = InputSource::from_args().puzzle_input(day);
+    let verdict = match &input_name {
+        Some(name) => Answers::read(day, name.as_deref()).verify(part, answer.as_deref()),
+        None => Verdict::Unknown,
+    };
+
+    print_result(
+        &result,
+        &part_str,
+        &format_duration(&stats),
+        &format_verdict(&verdict),
+    );
+
+    if stats.samples > 1 {
+        print_stats(&stats);
+    }
+
+    if let Some(allocs) = &allocs {
+        print_allocs(allocs);
+    }
+
+    report::emit(&Record {
+        step: Step::Part(part),
+        answer,
+        duration_nanos: stats.median.as_nanos(),
+        samples: stats.samples,
+        allocs,
+        status: match result {
+            Outcome::Answer(_) => Status::Ok,
+            Outcome::NoResult => Status::NoResult,
+            Outcome::Error(_) => Status::Error,
+        },
+        verified: match verdict {
+            Verdict::Unknown => None,
+            Verdict::Correct => Some(true),
+            Verdict::Wrong { .. } => Some(false),
+        },
+        message: match &result {
+            Outcome::Error(e) => Some(e.clone()),
+            Outcome::Answer(_) | Outcome::NoResult => None,
+        },
+    });
+
+    if let (Outcome::Answer(result), Some(name)) = (result, input_name) {
+        accept_result(&result, day, name.as_deref(), part);
+
+        match submit_result(&result, day, part) {
+            Some(Ok(submission)) if submission.verdict == submissions::Verdict::Correct => {
+                store_answer(&result, day, name.as_deref(), part);
+            }
+            Some(Err(e)) => eprintln!("{e}"),
+            _ => {}
+        }
+    }
+}
+
+/// Run the parser shared by both parts of a solution and report its execution time as `Parse`.
+/// Returns [`None`] if the parser panicked.
+pub fn run_parse<T>(func: impl Fn(&str) -> T, input: &str) -> Option<T> {
+    let (parsed, stats, allocs) = match run_timed(func, input, |_| print!("Parse: ✔")) {
+        Ok(run) => run,
+        Err(panic) => {
+            report_panic(&panic, "Parse", Step::Parse, None);
+            return None;
+        }
+    };
+
+    print!("\r");
+    println!("Parse: ✔{}", format_duration(&stats));
+
+    if stats.samples > 1 {
+        print_stats(&stats);
+    }
+
+    if let Some(allocs) = &allocs {
+        print_allocs(allocs);
+    }
+
+    report::emit(&Record {
+        step: Step::Parse,
+        answer: None,
+        duration_nanos: stats.median.as_nanos(),
+        samples: stats.samples,
+        allocs,
+        status: Status::Ok,
+        verified: None,
+        message: None,
+    });
+
+    Some(parsed)
+}
+
+/// Message and location of a panic caught while running a solution.
+#[derive(Debug)]
+struct Panic {
+    message: String,
+    location: Option<String>,
+}
+
+impl Display for Panic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "panicked at {location}: {}", self.message),
+            None => write!(f, "panicked: {}", self.message),
+        }
+    }
+}
+
+thread_local! {
+    // the panic hook runs on the panicking thread, which is the one calling `catch_panic`.
+    static LAST_PANIC: RefCell<Option<Panic>> = const { RefCell::new(None) };
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Installs a panic hook that records panics inside [`catch_panic`] to [`LAST_PANIC`].
+/// Panics anywhere else still go to the previous hook.
+fn install_panic_hook() {
+    static HOOK: Once = Once::new();
+
+    HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            if !CATCHING.get() {
+                default_hook(info);
+                return;
+            }
+
+            let payload = info.payload();
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(ToString::to_string)
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "Box<dyn Any>".into());
+
+            let panic = Panic {
+                message,
+                location: info.location().map(ToString::to_string),
+            };
+            LAST_PANIC.with(|last| *last.borrow_mut() = Some(panic));
+        }));
+    });
+}
+
+/// Runs `func`, catching a panic instead of unwinding out of the runner.
+/// The panic is not printed by the panic hook, so it is only reported once by the caller.
+fn catch_panic<T>(func: impl FnOnce() -> T) -> Result<T, Panic> {
+    install_panic_hook();
+
+    let was_catching = CATCHING.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(func));
+
+    CATCHING.set(was_catching);
+
+    result.map_err(|_| {
+        LAST_PANIC
+            .with(|last| last.borrow_mut().take())
+            .unwrap_or(Panic {
+                message: "unknown panic".into(),
+                location: None,
+            })
+    })
+}
+
+fn report_panic(panic: &Panic, step_str: &str, step: Step, verified: Option<bool>) {
+    print!("\r");
+    println!("{step_str}: {ANSI_BOLD}{panic}{ANSI_RESET}");
+
+    report::emit(&Record {
+        step,
+        answer: None,
+        duration_nanos: 0,
+        samples: 0,
+        allocs: None,
+        status: Status::Panicked,
+        verified,
+        message: Some(panic.to_string()),
+    });
+}
+
+/// Settings for benching a solution part, passed to solution binaries as command-line flags.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchConfig {
+    /// Fixed number of samples to collect. If not set, it is derived from `budget`.
+    pub samples: Option<u32>,
+    /// Approximate time to spend collecting samples.
+    pub budget: Duration,
+    /// Approximate time to spend running the solution before samples are collected.
+    pub warmup: Duration,
+    /// Count the allocations of each part, passed as `--alloc` (see [`super::alloc_counter`]).
+    pub alloc: bool,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            samples: None,
+            budget: Duration::from_secs(1),
+            warmup: Duration::from_millis(100),
+            alloc: false,
+        }
+    }
+}
+
+impl BenchConfig {
+    /// Reads the bench settings passed to a solution binary, returns [`None`] if `--time` is not set.
+    pub fn from_args() -> Option<Self> {
+        let mut args = pico_args::Arguments::from_env();
+
+        if !args.contains("--time") {
+            return None;
+        }
+
+        let default = Self::default();
+
+        Some(Self {
+            samples: args.opt_value_from_str("--samples").ok().flatten(),
+            budget: args
+                .opt_value_from_str("--budget")
+                .ok()
+                .flatten()
+                .map_or(default.budget, Duration::from_millis),
+            warmup: args
+                .opt_value_from_str("--warmup")
+                .ok()
+                .flatten()
+                .map_or(default.warmup, Duration::from_millis),
+            alloc: args.contains("--alloc"),
+        })
+    }
+
+    /// Builds the flags that [`BenchConfig::from_args`] reads.
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec!["--time".to_string()];
+
+        if self.alloc {
+            args.push("--alloc".into());
+        }
+
+        if let Some(samples) = self.samples {
+            args.extend(["--samples".into(), samples.to_string()]);
+        }
+
+        args.extend([
+            "--budget".into(),
+            self.budget.as_millis().to_string(),
+            "--warmup".into(),
+            self.warmup.as_millis().to_string(),
+        ]);
+
+        args
+    }
+}
+
+/// Input a solution binary runs on, selected with `--input-name <name>`, `--example` or `--input <path>`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputSource {
+    /// A puzzle input in `data/inputs`, either the default one or a named one (see [`super::puzzle_inputs`]).
+    Puzzle(Option<String>),
+    /// The example in `data/examples`. A part-specific file like `01-2.txt` is preferred if it exists.
+    Example,
+    File(PathBuf),
+    /// Standard input, passed as `--input -`.
+    Stdin,
+}
+
+impl InputSource {
+    /// Reads the input selection from command-line flags.
+    pub fn parse(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let name: Option<String> = args.opt_value_from_str("--input-name")?;
+        let example = args.contains("--example");
+        let path: Option<String> = args.opt_value_from_str("--input")?;
+
+        match (name, example, path) {
+            (None, true, None) => Ok(Self::Example),
+            (None, false, Some(path)) if path == "-" => Ok(Self::Stdin),
+            (None, false, Some(path)) => Ok(Self::File(path.into())),
+            (name, false, None) => Ok(Self::Puzzle(name)),
+            _ => Err(pico_args::Error::ArgumentParsingFailed {
+                cause: "only one of `--input-name`, `--example` and `--input` can be set".into(),
+            }),
+        }
+    }
+
+    /// Reads the input selection passed to a solution binary, defaulting to the puzzle input.
+    pub fn from_args() -> Self {
+        Self::parse(&mut pico_args::Arguments::from_env()).unwrap_or(Self::Puzzle(None))
+    }
+
+    /// Builds the flags that [`InputSource::from_args`] reads.
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            Self::Puzzle(None) => vec![],
+            Self::Puzzle(Some(name)) => vec!["--input-name".into(), name.clone()],
+            Self::Example => vec!["--example".into()],
+            Self::File(path) => vec!["--input".into(), path.display().to_string()],
+            Self::Stdin => vec!["--input".into(), "-".into()],
+        }
+    }
+
+    /// Reads the input for a part of a day. Standard input is only consumed once and shared by all parts.
+    pub fn read(&self, day: Day, part: u8) -> Result<String, (PathBuf, io::Error)> {
+        let data = PathBuf::from("data");
+
+        let path = match self {
+            Self::Puzzle(_) => puzzle_input_path(day, self.puzzle_input(day).flatten().as_deref()),
+            Self::Example => {
+                let part_path = data.join("examples").join(format!("{day}-{part}.txt"));
+                if part_path.exists() {
+                    part_path
+                } else {
+                    data.join("examples").join(format!("{day}.txt"))
+                }
+            }
+            Self::File(path) => path.clone(),
+            Self::Stdin => return read_stdin().map_err(|e| ("<stdin>".into(), e)),
+        };
+
+        fs::read_to_string(&path).map_err(|e| (path, e))
+    }
+
+    /// Name of the puzzle input this source reads, [`None`] for inputs that are not puzzle inputs.
+    /// The default source falls back to the first named input if a day has no `data/inputs/DD.txt`.
+    pub fn puzzle_input(&self, day: Day) -> Option<Option<String>> {
+        match self {
+            Self::Puzzle(Some(name)) => Some(Some(name.clone())),
+            Self::Puzzle(None) => Some(puzzle_inputs(day).into_iter().next().flatten()),
+            Self::Example | Self::File(_) | Self::Stdin => None,
+        }
+    }
+}
+
+/// Known answers for the input selected on the command line, [`None`] if it is not a puzzle input.
+fn known_answers(day: Day) -> Option<Answers> {
+    let name = InputSource::from_args().puzzle_input(day)?;
+    Some(Answers::read(day, name.as_deref()))
+}
+
+fn read_stdin() -> Result<String, io::Error> {
+    static STDIN: OnceLock<String> = OnceLock::new();
+
+    if let Some(input) = STDIN.get() {
+        return Ok(input.clone());
+    }
+
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input)?;
+    Ok(STDIN.get_or_init(|| input).clone())
+}
+
+/// Reads the input for a part from the source selected on the command line, exiting if it cannot be read.
+pub fn read_input(day: Day, part: u8) -> String {
+    match InputSource::from_args().read(day, part) {
+        Ok(input) => input,
+        Err((path, e)) => {
+            eprintln!("Could not read input \"{}\": {e}", path.display());
+            process::exit(1);
+        }
+    }
+}
+
+/// Whether a part should run, i.e. no other part was selected with `--part <part>`.
+pub fn runs_part(part: u8) -> bool {
+    let mut args = pico_args::Arguments::from_env();
+
+    match args.opt_value_from_str::<_, u8>("--part") {
+        Ok(Some(selected)) => selected == part,
+        _ => true,
+    }
+}
+
+/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
+///  1. in debug, the function is executed once.
+///  2. in release, the function is benched (see [`BenchConfig`] for how many samples are taken.)
+///
+/// A panic during the first execution is caught and returned as an error.
+/// With `--alloc`, the allocations of the first execution are counted as well.
+fn run_timed<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    hook: impl Fn(&T),
+) -> Result<(T, Stats, Option<AllocStats>), Panic> {
+    // NOTE: `--alloc` is also used without `--time`, so it is not read through `BenchConfig`.
+    let count_allocs = pico_args::Arguments::from_env().contains("--alloc");
+    if count_allocs && !alloc_counter::INSTALLED {
+        static WARNING: Once = Once::new();
+        WARNING.call_once(|| {
+            eprintln!(
+                "Warning: `--alloc` needs the `count-allocs` feature, no allocations are counted."
+            );
+        });
+    }
+
+    let (result, base_time, allocs) = catch_panic(|| {
+        let input = input.clone();
+
+        #[cfg(feature = "dhat-heap")]
+        let _profiler = dhat::Profiler::new_heap();
+
+        let timer = Instant::now();
+        let (result, allocs) = if count_allocs {
+            let (result, allocs) = alloc_counter::measure(|| func(input));
+            (result, Some(allocs))
+        } else {
+            (func(input), None)
+        };
+        (result, timer.elapsed(), allocs)
+    })?;
+
+    hook(&result);
+
+    let stats = match BenchConfig::from_args() {
+        Some(config) => bench(func, input, &base_time, &config),
+        None => Stats::from_samples(&[base_time]),
+    };
+
+    // NOTE: there is always at least one sample.
+    Ok((result, stats.unwrap(), allocs))
+}
+
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    config: &BenchConfig,
+) -> Option<Stats> {
+    let mut stdout = stdout();
+
+    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+    let _ = stdout.flush();
+
+    let base_nanos = cmp::max(base_time.as_nanos(), 10);
+
+    let warmup_iterations = (config.warmup.as_nanos() / base_nanos).clamp(1, 1000);
+
+    for _ in 0..warmup_iterations {
+        black_box(func(black_box(input.clone())));
+    }
+
+    let bench_iterations = config.samples.map_or_else(
+        || (config.budget.as_nanos() / base_nanos).clamp(10, 10000),
+        u128::from,
+    );
+
+    let mut timers: Vec<Duration> = vec![];
+
+    for _ in 0..bench_iterations {
+        // need a clone here to make the borrow checker happy.
+        let cloned = input.clone();
+        let timer = Instant::now();
+        black_box(func(black_box(cloned)));
+        timers.push(timer.elapsed());
+    }
+
+    Stats::from_samples(&timers)
+}
+
+fn format_duration(stats: &Stats) -> String {
+    let median = stats.median;
+    let samples = stats.samples;
+
+    if samples == 1 {
+        format!(" ({median:.1?})")
+    } else {
+        format!(" ({median:.1?} @ {samples} samples)")
+    }
+}
+
+fn format_verdict(verdict: &Verdict) -> String {
+    match verdict {
+        Verdict::Unknown => String::new(),
+        Verdict::Correct => " ✓".into(),
+        Verdict::Wrong { expected } => format!(" ✗ (expected {expected})"),
+    }
+}
+
+fn print_stats(stats: &Stats) {
+    let Stats {
+        min,
+        max,
+        std_dev,
+        p95,
+        p99,
+        outliers,
+        ..
+    } = stats;
+
+    println!(
+        "{ANSI_ITALIC}  min {min:.1?} · max {max:.1?} · σ {std_dev:.1?} · p95 {p95:.1?} · p99 {p99:.1?} · {outliers} outliers rejected{ANSI_RESET}"
+    );
+}
+
+fn print_allocs(allocs: &AllocStats) {
+    let AllocStats {
+        allocations,
+        bytes,
+        peak_bytes,
+    } = allocs;
+
+    println!(
+        "{ANSI_ITALIC}  {allocations} allocations · {} allocated · {} peak{ANSI_RESET}",
+        format_bytes(*bytes),
+        format_bytes(*peak_bytes)
+    );
+}
+
+fn print_result<T: Display>(
+    result: &Outcome<T>,
+    part: &str,
+    duration_str: &str,
+    verdict_str: &str,
+) {
+    let is_intermediate_result = duration_str.is_empty();
+
+    match result {
+        Outcome::Answer(result) => {
+            if result.to_string().contains('\n') {
+                let str = format!("{part}: ▼ {duration_str}{verdict_str}");
+                if is_intermediate_result {
+                    print!("{str}");
+                } else {
+                    print!("\r");
+                    println!("{str}");
+                    println!("{result}");
+                }
+            } else {
+                let str =
+                    format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}{verdict_str}");
+                if is_intermediate_result {
+                    print!("{str}");
+                } else {
+                    print!("\r");
+                    println!("{str}");
+                }
+            }
+        }
+        Outcome::NoResult => {
+            if is_intermediate_result {
+                print!("{part}: ✖");
+            } else {
+                print!("\r");
+                println!("{part}: ✖{verdict_str}             ");
+            }
+        }
+        Outcome::Error(e) => {
+            if is_intermediate_result {
+                print!("{part}: ✖");
+            } else {
+                print!("\r");
+                println!("{part}: {ANSI_BOLD}error: {e}{ANSI_RESET}{verdict_str}");
+            }
+        }
+    }
+}
+
+/// Parse the arguments passed to `solve` and try to submit one part of the solution if we are in `--release` mode.
+fn submit_result<T: Display>(
+    result: &T,
+    day: Day,
+    part: u8,
+) -> Option<Result<Submission, aoc_client::AocClientError>> {
+    let args: Vec<String> = env::args().collect();
+
+    if !args.contains(&"--submit".into()) {
+        return None;
+    }
+
+    if args.len() < 3 {
+        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+        process::exit(1);
+    }
+
+    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+
+    let Ok(part_submit) = args[part_index].parse::<u8>() else {
+        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+        process::exit(1);
+    };
+
+    if part_submit != part {
+        return None;
+    }
+
+    println!("Submitting result...");
+    Some(aoc_client::submit(day, part, &result.to_string()))
+}
+
+/// Parse the arguments passed to `solve` and record one part of the solution as its known answer if `--accept <part>` is set.
+fn accept_result<T: Display>(result: &T, day: Day, input: Option<&str>, part: u8) {
+    let mut args = pico_args::Arguments::from_env();
+
+    let Ok(Some(part_accept)) = args.opt_value_from_str::<_, u8>("--accept") else {
+        return;
+    };
+
+    if part_accept == part {
+        store_answer(result, day, input, part);
+    }
+}
+
+fn store_answer<T: Display>(result: &T, day: Day, input: Option<&str>, part: u8) {
+    match answers::accept(day, input, part, result.to_string()) {
+        Ok(path) => println!("Stored answer for part {part} in \"{}\".", path.display()),
+        Err(e) => eprintln!("Failed to store answer: {e}"),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::catch_panic;
+
+    mod input_source {
+        use crate::template::runner::InputSource;
+        use std::path::PathBuf;
+
+        fn parse(args: &[&str]) -> Result<InputSource, pico_args::Error> {
+            let mut args = pico_args::Arguments::from_vec(args.iter().map(Into::into).collect());
+            InputSource::parse(&mut args)
+        }
+
+        #[test]
+        fn parses_sources() {
+            assert_eq!(parse(&[]).unwrap(), InputSource::Puzzle(None));
+            assert_eq!(
+                parse(&["--input-name", "alice"]).unwrap(),
+                InputSource::Puzzle(Some("alice".into()))
+            );
+            assert_eq!(parse(&["--example"]).unwrap(), InputSource::Example);
+            assert_eq!(parse(&["--input", "-"]).unwrap(), InputSource::Stdin);
+            assert_eq!(
+                parse(&["--input", "data/inputs/01-alt.txt"]).unwrap(),
+                InputSource::File(PathBuf::from("data/inputs/01-alt.txt"))
+            );
+        }
+
+        #[test]
+        fn rejects_conflicting_sources() {
+            assert_eq!(parse(&["--example", "--input", "-"]).is_err(), true);
+            assert_eq!(
+                parse(&["--input-name", "alice", "--example"]).is_err(),
+                true
+            );
+        }
+
+        #[test]
+        fn round_trips_args() {
+            for source in [
+                InputSource::Puzzle(None),
+                InputSource::Puzzle(Some("alice".into())),
+                InputSource::Example,
+                InputSource::Stdin,
+                InputSource::File("in.txt".into()),
+            ] {
+                let args: Vec<_> = source.to_args();
+                assert_eq!(
+                    parse(&args.iter().map(String::as_str).collect::<Vec<_>>()).unwrap(),
+                    source
+                );
+            }
+        }
+    }
+
+    mod outcome {
+        use crate::template::runner::{Outcome, PartResult};
+
+        #[test]
+        fn converts_options() {
+            assert!(matches!(Some(1).into_outcome(), Outcome::Answer(1)));
+            assert!(matches!(None::<u32>.into_outcome(), Outcome::NoResult));
+        }
+
+        #[test]
+        fn converts_results() {
+            assert!(matches!(
+                Ok::<u32, String>(1).into_outcome(),
+                Outcome::Answer(1)
+            ));
+            assert!(matches!(
+                Err::<u32, &str>("bad input").into_outcome(),
+                Outcome::Error(e) if e == "bad input"
+            ));
+        }
+    }
+
+    #[test]
+    fn passes_through_results() {
+        assert_eq!(catch_panic(|| 42).unwrap(), 42);
+    }
+
+    #[test]
+    fn catches_panics_with_location() {
+        let panic = catch_panic(|| -> u32 { panic!("oops {}", 1) }).unwrap_err();
+        assert_eq!(panic.message, "oops 1");
+        assert_eq!(
+            panic
+                .location
+                .unwrap()
+                .starts_with("template/src/template/runner.rs:"),
+            true
+        );
+    }
+
+    #[test]
+    fn catches_static_panic_messages() {
+        let panic = catch_panic(|| -> u32 { None.expect("should be int") }).unwrap_err();
+        assert_eq!(panic.message, "should be int");
+        assert_eq!(
+            panic
+                .to_string()
+                .starts_with("panicked at template/src/template/runner.rs:"),
+            true
+        );
+    }
+}
@@ -0,0 +1,210 @@
+/// Summary statistics over the samples collected while benching a solution part.
+use std::time::Duration;
+
+/// Samples further than this many interquartile ranges outside of the first / third quartile are rejected as outliers.
+const OUTLIER_IQR_FACTOR: f64 = 1.5;
+
+/// Statistical report for a set of benchmark samples.
+///
+/// `samples` counts every measured run, `outliers` how many of those were rejected before the
+/// remaining figures were computed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub outliers: usize,
+    pub mean: Duration,
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub std_dev: Duration,
+    pub p95: Duration,
+    pub p99: Duration,
+}
+
+impl Stats {
+    /// Computes statistics for the given samples, returns [`None`] if there are none.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        sorted.sort_unstable_by(f64::total_cmp);
+
+        let q1 = percentile(&sorted, 25.0);
+        let q3 = percentile(&sorted, 75.0);
+        let iqr = q3 - q1;
+        let (lower, upper) = (q1 - OUTLIER_IQR_FACTOR * iqr, q3 + OUTLIER_IQR_FACTOR * iqr);
+
+        // NOTE: every value between the quartiles falls within the fences, so this is never empty.
+        let kept: Vec<f64> = sorted
+            .iter()
+            .copied()
+            .filter(|x| *x >= lower && *x <= upper)
+            .collect();
+
+        let n = kept.len() as f64;
+        let mean = kept.iter().sum::<f64>() / n;
+        let variance = if kept.len() > 1 {
+            kept.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)
+        } else {
+            0.0
+        };
+
+        Some(Stats {
+            samples: samples.len(),
+            outliers: samples.len() - kept.len(),
+            mean: nanos(mean),
+            median: nanos(percentile(&kept, 50.0)),
+            min: nanos(kept[0]),
+            max: nanos(kept[kept.len() - 1]),
+            std_dev: nanos(variance.sqrt()),
+            p95: nanos(percentile(&kept, 95.0)),
+            p99: nanos(percentile(&kept, 99.0)),
+        })
+    }
+}
+
+/// Ratio of two runtimes measured over several rounds, e.g. `before / after`, with a 95% confidence interval.
+///
+/// The estimate is the geometric mean of the per-round ratios, the interval a t-interval of their logarithms, so that
+/// a speedup and the matching slowdown are treated alike.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ratio {
+    pub rounds: usize,
+    pub estimate: f64,
+    pub low: f64,
+    pub high: f64,
+}
+
+impl Ratio {
+    /// Computes the ratio of paired runtimes, returns [`None`] if there are none or one of them is not positive.
+    pub fn from_pairs(pairs: &[(f64, f64)]) -> Option<Self> {
+        if pairs.is_empty() || pairs.iter().any(|(a, b)| *a <= 0.0 || *b <= 0.0) {
+            return None;
+        }
+
+        let logs: Vec<f64> = pairs.iter().map(|(a, b)| (a / b).ln()).collect();
+        let n = logs.len() as f64;
+        let mean = logs.iter().sum::<f64>() / n;
+
+        let margin = if logs.len() > 1 {
+            let variance = logs.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+            t_value(logs.len() - 1) * (variance / n).sqrt()
+        } else {
+            0.0
+        };
+
+        Some(Ratio {
+            rounds: logs.len(),
+            estimate: mean.exp(),
+            low: (mean - margin).exp(),
+            high: (mean + margin).exp(),
+        })
+    }
+}
+
+/// Two-sided 95% quantile of the t-distribution with `df` degrees of freedom.
+fn t_value(df: usize) -> f64 {
+    const TABLE: [f64; 30] = [
+        12.706, 4.303, 3.182, 2.776, 2.571, 2.447, 2.365, 2.306, 2.262, 2.228, 2.201, 2.179, 2.160,
+        2.145, 2.131, 2.120, 2.110, 2.101, 2.093, 2.086, 2.080, 2.074, 2.069, 2.064, 2.060, 2.056,
+        2.052, 2.048, 2.045, 2.042,
+    ];
+    TABLE.get(df.saturating_sub(1)).copied().unwrap_or(1.96)
+}
+
+/// Linearly interpolated percentile `p` (0 to 100) of a sorted, non-empty slice.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p / 100.0 * (sorted.len() - 1) as f64;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let (lo, hi) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[lo] + (sorted[hi] - sorted[lo]) * (rank - lo as f64)
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn nanos(x: f64) -> Duration {
+    Duration::from_nanos(x.round() as u64)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Ratio, Stats};
+    use std::time::Duration;
+
+    fn ns(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_nanos).collect()
+    }
+
+    #[test]
+    fn handles_empty_samples() {
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn handles_single_sample() {
+        let stats = Stats::from_samples(&ns(&[42])).unwrap();
+        assert_eq!(stats.samples, 1);
+        assert_eq!(stats.outliers, 0);
+        assert_eq!(stats.median, Duration::from_nanos(42));
+        assert_eq!(stats.min, Duration::from_nanos(42));
+        assert_eq!(stats.max, Duration::from_nanos(42));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+    }
+
+    #[test]
+    fn computes_distribution() {
+        let stats = Stats::from_samples(&ns(&[40, 10, 30, 20, 50])).unwrap();
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.outliers, 0);
+        assert_eq!(stats.mean, Duration::from_nanos(30));
+        assert_eq!(stats.median, Duration::from_nanos(30));
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.max, Duration::from_nanos(50));
+        assert_eq!(stats.std_dev, Duration::from_nanos(16));
+        assert_eq!(stats.p95, Duration::from_nanos(48));
+        assert_eq!(stats.p99, Duration::from_nanos(50));
+    }
+
+    #[test]
+    fn interpolates_even_median() {
+        let stats = Stats::from_samples(&ns(&[10, 20, 30, 40])).unwrap();
+        assert_eq!(stats.median, Duration::from_nanos(25));
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats = Stats::from_samples(&ns(&[10, 11, 12, 11, 10, 12, 11, 1000])).unwrap();
+        assert_eq!(stats.samples, 8);
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.max, Duration::from_nanos(12));
+        assert_eq!(stats.median, Duration::from_nanos(11));
+    }
+
+    #[test]
+    fn handles_invalid_pairs() {
+        assert_eq!(Ratio::from_pairs(&[]), None);
+        assert_eq!(Ratio::from_pairs(&[(10.0, 0.0)]), None);
+    }
+
+    #[test]
+    fn computes_constant_ratio() {
+        let ratio = Ratio::from_pairs(&[(20.0, 10.0), (40.0, 20.0), (30.0, 15.0)]).unwrap();
+        assert_eq!(ratio.rounds, 3);
+        assert_eq!((ratio.estimate - 2.0).abs() < 1e-9, true);
+        assert_eq!((ratio.low - 2.0).abs() < 1e-9, true);
+        assert_eq!((ratio.high - 2.0).abs() < 1e-9, true);
+    }
+
+    #[test]
+    fn computes_ratio_interval() {
+        let ratio = Ratio::from_pairs(&[(20.0, 10.0), (10.0, 10.0), (40.0, 10.0)]).unwrap();
+        assert_eq!((ratio.estimate - 2.0).abs() < 1e-9, true);
+        assert_eq!(ratio.low < 1.0, true);
+        assert_eq!(ratio.high > 4.0, true);
+        // the interval is symmetric on a log scale.
+        assert_eq!((ratio.low * ratio.high - 4.0).abs() < 1e-9, true);
+    }
+}
@@ -0,0 +1,478 @@
+/// History of the answers submitted to Advent of Code, stored per day in `data/submissions/DD.json`.
+///
+/// Every submission is recorded with the verdict parsed from the response. The history is used to refuse answers
+/// that are known to be wrong before they are submitted again, and to warn about the cooldown after a wrong answer.
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::SystemTime,
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::Day;
+
+/// Verdict of a submission, parsed from the response of the website.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// The answer is wrong, without a hint in which direction.
+    Wrong,
+    /// The answer was submitted during the cooldown of a previous one and was not checked.
+    TooRecent,
+    /// The part is not the one to solve next, e.g. because it has been solved already.
+    WrongLevel,
+    /// The response could not be parsed.
+    Unknown,
+}
+
+impl Verdict {
+    /// Whether the submitted answer is known to be wrong.
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+
+    /// Describes the verdict for humans.
+    pub fn describe(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+            Verdict::TooRecent => "too recent",
+            Verdict::WrongLevel => "for the wrong level",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Submission {
+    pub part: u8,
+    pub answer: String,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub verdict: Verdict,
+    /// Seconds to wait before submitting again, if the response asked for it.
+    pub wait: Option<u64>,
+}
+
+/// All submissions for a day, oldest first.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct History {
+    pub submissions: Vec<Submission>,
+}
+
+fn get_history_path(day: Day) -> PathBuf {
+    PathBuf::from("data")
+        .join("submissions")
+        .join(format!("{day}.json"))
+}
+
+impl History {
+    /// Reads the submissions for a day. If not present, returns an empty history.
+    /// Fails if the file cannot be parsed, as storing over it would lose the known wrong answers.
+    pub fn read(day: Day) -> Result<Self, String> {
+        let path = get_history_path(day);
+        History::read_from_path(&path)
+            .map_err(|e| format!("\"{}\" is invalid: {e}", path.display()))
+    }
+
+    fn read_from_path(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(s) => History::try_from(s),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Writes the submissions for a day, creating `data/submissions` if needed.
+    pub fn store(&self, day: Day) -> Result<(), io::Error> {
+        let path = get_history_path(day);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(path)?;
+        json.format_to(&mut file)
+    }
+
+    /// Checks an answer against previous submissions for the same part before it is submitted.
+    /// Returns why the answer should not be submitted if it is known to be wrong.
+    pub fn check(&self, part: u8, answer: &str) -> Result<(), String> {
+        let submissions: Vec<&Submission> =
+            self.submissions.iter().filter(|s| s.part == part).collect();
+
+        if let Some(correct) = submissions.iter().find(|s| s.verdict == Verdict::Correct) {
+            return Err(format!(
+                "part {part} was already solved with `{}`.",
+                correct.answer
+            ));
+        }
+
+        if let Some(wrong) = submissions
+            .iter()
+            .find(|s| s.answer == answer && s.verdict.is_wrong())
+        {
+            return Err(format!(
+                "`{answer}` was already submitted and is {}.",
+                wrong.verdict.describe()
+            ));
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+
+        let bound = |verdict: Verdict| {
+            submissions
+                .iter()
+                .filter(move |s| s.verdict == verdict)
+                .filter_map(|s| s.answer.parse::<i128>().ok())
+        };
+
+        if let Some(high) = bound(Verdict::TooHigh).min().filter(|high| value >= *high) {
+            return Err(format!(
+                "`{answer}` is not below `{high}`, which is too high."
+            ));
+        }
+
+        if let Some(low) = bound(Verdict::TooLow).max().filter(|low| value <= *low) {
+            return Err(format!(
+                "`{answer}` is not above `{low}`, which is too low."
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Seconds left until the cooldown of the latest submission has passed, [`None`] if it has passed already.
+    pub fn cooldown(&self, now: u64) -> Option<u64> {
+        let last = self.submissions.last()?;
+        let until = last.timestamp + last.wait?;
+        (until > now).then(|| until - now)
+    }
+
+    pub fn push(&mut self, submission: Submission) {
+        self.submissions.push(submission);
+    }
+}
+
+/// Parses the verdict and the time to wait before the next submission from a response.
+pub fn parse_response(response: &str) -> (Verdict, Option<u64>) {
+    let verdict = if response.contains("That's the right answer") {
+        Verdict::Correct
+    } else if response.contains("your answer is too high") {
+        Verdict::TooHigh
+    } else if response.contains("your answer is too low") {
+        Verdict::TooLow
+    } else if response.contains("That's not the right answer") {
+        Verdict::Wrong
+    } else if response.contains("You gave an answer too recently") {
+        Verdict::TooRecent
+    } else if response.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        Verdict::Unknown
+    };
+
+    (verdict, parse_wait(response))
+}
+
+/// Parses "please wait 5 minutes before trying again" and "You have 1m 30s left to wait".
+fn parse_wait(response: &str) -> Option<u64> {
+    if let Some(end) = response.find("s left to wait") {
+        let start = response[..end].rfind("You have ")? + "You have ".len();
+
+        return response[start..end]
+            .split_whitespace()
+            .map(|x| match x.strip_suffix('m') {
+                Some(minutes) => minutes.parse::<u64>().ok().map(|m| m * 60),
+                None => x.parse::<u64>().ok(),
+            })
+            .sum();
+    }
+
+    let response = response.to_lowercase();
+    response.match_indices("wait ").find_map(|(i, x)| {
+        let mut words = response[i + x.len()..].split_whitespace();
+        let minutes = match words.next()? {
+            "one" => 1,
+            x => x.parse().ok()?,
+        };
+        words.next()?.starts_with("minute").then_some(minutes * 60)
+    })
+}
+
+/// Current time in seconds since the unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl std::fmt::Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Wrong => "wrong",
+            Verdict::TooRecent => "too_recent",
+            Verdict::WrongLevel => "wrong_level",
+            Verdict::Unknown => "unknown",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too_high" => Ok(Verdict::TooHigh),
+            "too_low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            "too_recent" => Ok(Verdict::TooRecent),
+            "wrong_level" => Ok(Verdict::WrongLevel),
+            "unknown" => Ok(Verdict::Unknown),
+            s => Err(format!("unknown verdict `{s}`.")),
+        }
+    }
+}
+
+impl From<&History> for JsonValue {
+    fn from(value: &History) -> Self {
+        let submissions = value
+            .submissions
+            .iter()
+            .map(|submission| {
+                let mut map: HashMap<String, JsonValue> = HashMap::new();
+                map.insert("part".into(), JsonValue::Number(submission.part.into()));
+                map.insert(
+                    "answer".into(),
+                    JsonValue::String(submission.answer.clone()),
+                );
+                #[allow(clippy::cast_precision_loss)]
+                map.insert(
+                    "timestamp".into(),
+                    JsonValue::Number(submission.timestamp as f64),
+                );
+                map.insert(
+                    "verdict".into(),
+                    JsonValue::String(submission.verdict.to_string()),
+                );
+                #[allow(clippy::cast_precision_loss)]
+                map.insert(
+                    "wait".into(),
+                    match submission.wait {
+                        Some(x) => JsonValue::Number(x as f64),
+                        None => JsonValue::Null,
+                    },
+                );
+                JsonValue::Object(map)
+            })
+            .collect();
+
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        map.insert("submissions".into(), JsonValue::Array(submissions));
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for History {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let submissions = json
+            .get("submissions")
+            .ok_or("expected JSON document to contain submissions.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected submissions to be an array.")?
+            .iter()
+            .map(Submission::try_from)
+            .collect::<Result<_, _>>()?;
+
+        Ok(History { submissions })
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected submission to be an object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .copied()
+                .ok_or(format!("expected submission.{key} to be a number."))
+        };
+
+        let string = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<String>())
+                .ok_or(format!("expected submission.{key} to be a string."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(Submission {
+            part: number("part")? as u8,
+            answer: string("answer")?.clone(),
+            timestamp: number("timestamp")? as u64,
+            verdict: string("verdict")?.parse()?,
+            wait: json
+                .get("wait")
+                .and_then(|v| v.get::<f64>())
+                .map(|x| *x as u64),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_response, History, Submission, Verdict};
+    use std::{env, fs, process};
+    use tinyjson::JsonValue;
+
+    #[test]
+    fn reads_missing_and_invalid_files() {
+        let dir = env::temp_dir().join(format!("aoc-submissions-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("05.json");
+
+        assert_eq!(
+            History::read_from_path(&path)
+                .unwrap()
+                .submissions
+                .is_empty(),
+            true
+        );
+
+        fs::write(&path, r#"{ "submissions": [{ "part": 1 "#).unwrap();
+        assert_eq!(History::read_from_path(&path).is_err(), true);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    fn submission(part: u8, answer: &str, verdict: Verdict) -> Submission {
+        Submission {
+            part,
+            answer: answer.into(),
+            timestamp: 1_000,
+            verdict,
+            wait: None,
+        }
+    }
+
+    #[test]
+    fn parses_responses() {
+        assert_eq!(
+            parse_response("That's the right answer! You are one gold star closer."),
+            (Verdict::Correct, None)
+        );
+        assert_eq!(
+            parse_response("That's not the right answer; your answer is too high. If you're stuck, [...] Please wait one minute before trying again."),
+            (Verdict::TooHigh, Some(60))
+        );
+        assert_eq!(
+            parse_response("That's not the right answer; your answer is too low. [...] please wait 5 minutes before trying again."),
+            (Verdict::TooLow, Some(300))
+        );
+        assert_eq!(
+            parse_response(
+                "That's not the right answer. Curiously, it's the right answer for someone else."
+            ),
+            (Verdict::Wrong, None)
+        );
+        assert_eq!(
+            parse_response("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait."),
+            (Verdict::TooRecent, Some(83))
+        );
+        assert_eq!(
+            parse_response(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            (Verdict::WrongLevel, None)
+        );
+        assert_eq!(parse_response("???"), (Verdict::Unknown, None));
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let history = History {
+            submissions: vec![
+                submission(1, "abc", Verdict::Wrong),
+                submission(1, "100", Verdict::TooHigh),
+                submission(1, "50", Verdict::TooLow),
+                submission(2, "75", Verdict::TooRecent),
+            ],
+        };
+
+        assert_eq!(history.check(1, "abc").is_err(), true);
+        assert_eq!(history.check(1, "100").is_err(), true);
+        assert_eq!(history.check(1, "120").is_err(), true);
+        assert_eq!(history.check(1, "50").is_err(), true);
+        assert_eq!(history.check(1, "-3").is_err(), true);
+        assert_eq!(history.check(1, "75"), Ok(()));
+        assert_eq!(history.check(1, "xyz"), Ok(()));
+        assert_eq!(history.check(2, "75"), Ok(()));
+        assert_eq!(history.check(2, "120"), Ok(()));
+    }
+
+    #[test]
+    fn refuses_solved_parts() {
+        let history = History {
+            submissions: vec![submission(1, "42", Verdict::Correct)],
+        };
+        assert_eq!(history.check(1, "43").is_err(), true);
+        assert_eq!(history.check(2, "43"), Ok(()));
+    }
+
+    #[test]
+    fn tracks_cooldown() {
+        let mut history = History::default();
+        assert_eq!(history.cooldown(1_000), None);
+
+        history.push(Submission {
+            wait: Some(60),
+            ..submission(1, "100", Verdict::TooHigh)
+        });
+        assert_eq!(history.cooldown(1_030), Some(30));
+        assert_eq!(history.cooldown(1_060), None);
+    }
+
+    #[test]
+    fn round_trips_history() {
+        let history = History {
+            submissions: vec![
+                Submission {
+                    wait: Some(60),
+                    ..submission(1, "100", Verdict::TooHigh)
+                },
+                submission(1, "42", Verdict::Correct),
+            ],
+        };
+        let json = JsonValue::from(&history).stringify().unwrap();
+        assert_eq!(History::try_from(json).unwrap(), history);
+    }
+}
@@ -0,0 +1,694 @@
+use std::{collections::HashMap, fs, io::Error, path::Path, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{
+    alloc_counter::AllocStats,
+    history::{Run, RUNS_PER_DAY},
+    report::{Status, Step},
+    Day,
+};
+
+static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+
+/// Represents benchmark times for a single day.
+#[derive(Clone, Debug)]
+pub struct Timing {
+    pub day: Day,
+    pub parse: Option<String>,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+    pub total_nanos: f64,
+    /// Set if a step panicked or the run was stopped by a resource limit.
+    pub status: Option<Status>,
+    /// Allocations per step, only present for runs with `--alloc`.
+    pub allocs: Vec<(Step, AllocStats)>,
+    /// Runtime per step, used to compare runs. Timings stored before this was tracked do not have it.
+    pub nanos: Vec<(Step, f64)>,
+}
+
+impl Timing {
+    /// Runtime of a step in nanoseconds, if the timing has one.
+    pub fn step_nanos(&self, step: Step) -> Option<f64> {
+        self.nanos
+            .iter()
+            .find(|(s, nanos)| *s == step && *nanos > 0.0)
+            .map(|(_, nanos)| *nanos)
+    }
+}
+
+/// Represents benchmark times for a set of days, along with the runs they were collected in.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Timings {
+    /// Latest timing of every day.
+    pub data: Vec<Timing>,
+    /// Stored runs, oldest first.
+    pub history: Vec<Run>,
+}
+
+impl Timings {
+    /// Dehydrate timings to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate timings from a JSON file. If not present, returns empty timings.
+    /// A file that cannot be parsed is reported and treated as empty as well.
+    pub fn read_from_file() -> Self {
+        Self::read_from_path(Path::new(TIMINGS_FILE_PATH))
+    }
+
+    /// Same as [`Timings::read_from_file`] for the timings at `path`, e.g. those of another year.
+    pub fn read_from_path(path: &Path) -> Self {
+        let Ok(s) = fs::read_to_string(path) else {
+            return Timings::default();
+        };
+
+        Timings::try_from(s).unwrap_or_else(|e| {
+            eprintln!("Failed to read \"{}\": {e}", path.display());
+            Timings::default()
+        })
+    }
+
+    /// Merge two sets of timings, overwriting `self` with `other` if present.
+    /// The history of `self` is kept, see [`Timings::record`] to add the run of `new` to it.
+    pub fn merge(&self, new: &Self) -> Self {
+        let mut data: Vec<Timing> = vec![];
+
+        for timing in &new.data {
+            data.push(timing.clone());
+        }
+
+        for timing in &self.data {
+            if !data.iter().any(|t| t.day == timing.day) {
+                data.push(timing.clone());
+            }
+        }
+
+        data.sort_unstable_by_key(|a| a.day);
+        Timings {
+            data,
+            history: self.history.clone(),
+        }
+    }
+
+    /// Appends a run to the history, keeping only the days that ran and the latest [`RUNS_PER_DAY`] runs of each day.
+    pub fn record(&mut self, mut run: Run) {
+        run.data.retain(|timing| !timing.nanos.is_empty());
+        self.history.push(run);
+
+        let mut runs: HashMap<Day, usize> = HashMap::new();
+        for run in self.history.iter_mut().rev() {
+            run.data.retain(|timing| {
+                let count = runs.entry(timing.day).or_default();
+                *count += 1;
+                *count <= RUNS_PER_DAY
+            });
+        }
+        self.history.retain(|run| !run.data.is_empty());
+    }
+
+    /// Sum up total duration of timings as millis.
+    pub fn total_millis(&self) -> f64 {
+        self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
+    }
+
+    pub fn is_day_complete(&self, day: Day) -> bool {
+        self.data
+            .iter()
+            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Timings> for JsonValue {
+    fn from(value: Timings) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        if !value.history.is_empty() {
+            map.insert(
+                "history".into(),
+                JsonValue::Array(value.history.iter().map(JsonValue::from).collect()),
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Timings {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let json_data = json
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        // NOTE: timings stored before runs were recorded do not have this key.
+        let history = match json.get("history") {
+            None | Some(JsonValue::Null) => vec![],
+            Some(v) => v
+                .get::<Vec<JsonValue>>()
+                .ok_or("expected `json.history` to be an array.")?
+                .iter()
+                .map(Run::try_from)
+                .collect::<Result<_, _>>()?,
+        };
+
+        Ok(Timings {
+            data: json_data
+                .iter()
+                .map(Timing::try_from)
+                .collect::<Result<_, _>>()?,
+            history,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Timing> for JsonValue {
+    fn from(value: &Timing) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
+
+        let parse = value.parse.clone().map(JsonValue::String);
+        let part_1 = value.part_1.clone().map(JsonValue::String);
+        let part_2 = value.part_2.clone().map(JsonValue::String);
+
+        map.insert(
+            "parse".into(),
+            match parse {
+                Some(x) => x,
+                None => JsonValue::Null,
+            },
+        );
+
+        if let Some(status) = &value.status {
+            map.insert("status".into(), JsonValue::String(status.to_string()));
+        }
+
+        if !value.nanos.is_empty() {
+            let nanos = value
+                .nanos
+                .iter()
+                .map(|(step, nanos)| (step.to_string(), JsonValue::Number(*nanos)))
+                .collect();
+            map.insert("nanos".into(), JsonValue::Object(nanos));
+        }
+
+        if !value.allocs.is_empty() {
+            let allocs = value
+                .allocs
+                .iter()
+                .map(|(step, allocs)| (step.to_string(), JsonValue::from(allocs)))
+                .collect();
+            map.insert("allocs".into(), JsonValue::Object(allocs));
+        }
+
+        map.insert(
+            "part_1".into(),
+            match part_1 {
+                Some(x) => x,
+                None => JsonValue::Null,
+            },
+        );
+
+        map.insert(
+            "part_2".into(),
+            match part_2 {
+                Some(x) => x,
+                None => JsonValue::Null,
+            },
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Timing {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected timing.day to be a Day struct.")?;
+
+        // NOTE: timings stored before parse times were tracked do not have this key.
+        let parse = json
+            .get("parse")
+            .and_then(|v| if v.is_null() { None } else { v.get::<String>() });
+
+        let part_1 = json
+            .get("part_1")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected timing.part_1 to be null or string.")?;
+
+        let part_2 = json
+            .get("part_2")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected timing.part_2 to be null or string.")?;
+
+        let total_nanos = json
+            .get("total_nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected timing.total_nanos to be a number.")?;
+
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .map(|s| s.parse::<Status>())
+            .transpose()?;
+
+        let mut allocs = match json.get("allocs") {
+            None | Some(JsonValue::Null) => vec![],
+            Some(v) => v
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or("Expected timing.allocs to be an object.")?
+                .iter()
+                .map(|(step, allocs)| Ok((step.parse()?, AllocStats::try_from(allocs)?)))
+                .collect::<Result<Vec<_>, String>>()?,
+        };
+        allocs.sort_unstable_by_key(|(step, _)| step_order(*step));
+
+        let mut nanos = match json.get("nanos") {
+            None | Some(JsonValue::Null) => vec![],
+            Some(v) => v
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or("Expected timing.nanos to be an object.")?
+                .iter()
+                .map(|(step, nanos)| {
+                    let nanos = nanos
+                        .get::<f64>()
+                        .ok_or("Expected timing.nanos to hold numbers.")?;
+                    Ok((step.parse()?, *nanos))
+                })
+                .collect::<Result<Vec<_>, String>>()?,
+        };
+        nanos.sort_unstable_by_key(|(step, _)| step_order(*step));
+
+        Ok(Timing {
+            day,
+            status,
+            allocs,
+            nanos,
+            parse: parse.cloned(),
+            part_1: part_1.cloned(),
+            part_2: part_2.cloned(),
+            total_nanos,
+        })
+    }
+}
+
+fn step_order(step: Step) -> u8 {
+    match step {
+        Step::Parse => 0,
+        Step::Part(part) => part,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use crate::day;
+
+    use super::{Timing, Timings};
+
+    fn get_mock_timings() -> Timings {
+        Timings {
+            history: vec![],
+            data: vec![
+                Timing {
+                    day: day!(1),
+                    parse: None,
+                    part_1: Some("10ms".into()),
+                    part_2: Some("20ms".into()),
+                    total_nanos: 3e+10,
+                    status: None,
+                    allocs: vec![],
+                    nanos: vec![],
+                },
+                Timing {
+                    day: day!(2),
+                    parse: None,
+                    part_1: Some("30ms".into()),
+                    part_2: Some("40ms".into()),
+                    total_nanos: 7e+10,
+                    status: None,
+                    allocs: vec![],
+                    nanos: vec![],
+                },
+                Timing {
+                    day: day!(4),
+                    parse: None,
+                    part_1: Some("40ms".into()),
+                    part_2: None,
+                    total_nanos: 4e+10,
+                    status: None,
+                    allocs: vec![],
+                    nanos: vec![],
+                },
+            ],
+        }
+    }
+
+    mod deserialization {
+        use crate::{
+            day,
+            template::{alloc_counter::AllocStats, report::Step, timings::Timings},
+        };
+
+        #[test]
+        fn handles_json_timings() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data.len(), 1);
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.day, day!(1));
+            assert_eq!(timing.parse, None);
+            assert_eq!(timing.part_1, Some("1ms".to_string()));
+            assert_eq!(timing.part_2, None);
+            assert_eq!(timing.total_nanos, 1_000_000_000_f64);
+        }
+
+        #[test]
+        fn handles_json_timings_with_parse() {
+            let json = r#"{ "data": [{ "day": "01", "parse": "2µs", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.parse, Some("2µs".to_string()));
+            assert_eq!(timing.part_1, Some("1ms".to_string()));
+        }
+
+        #[test]
+        fn handles_json_timings_with_allocs() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000, "allocs": { "1": { "allocations": 3, "bytes": 96, "peak_bytes": 64 }, "parse": { "allocations": 1, "bytes": 8, "peak_bytes": 8 } } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(
+                timing.allocs,
+                vec![
+                    (
+                        Step::Parse,
+                        AllocStats {
+                            allocations: 1,
+                            bytes: 8,
+                            peak_bytes: 8
+                        }
+                    ),
+                    (
+                        Step::Part(1),
+                        AllocStats {
+                            allocations: 3,
+                            bytes: 96,
+                            peak_bytes: 64
+                        }
+                    ),
+                ]
+            );
+        }
+
+        #[test]
+        fn handles_json_timings_with_history() {
+            let json = r#"{ "data": [], "history": [{ "timestamp": 1733400000, "commit": "abc1234", "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "nanos": { "1": 1000000 } }] }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.history.len(), 1);
+            let run = timings.history.first().unwrap();
+            assert_eq!(run.timestamp, 1_733_400_000);
+            assert_eq!(run.commit, Some("abc1234".to_string()));
+            assert_eq!(run.data[0].nanos, vec![(Step::Part(1), 1_000_000_f64)]);
+        }
+
+        #[test]
+        fn handles_empty_timings() {
+            let json = r#"{ "data": [] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data.len(), 0);
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_invalid_json() {
+            let json = r#"{}"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_malformed_timings() {
+            let json = r#"{ "data": [{ "day": "01" }, { "day": "26" }, { "day": "02", "part_2": null, "total_nanos": 0 }] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
+    }
+
+    mod serialization {
+        use super::get_mock_timings;
+        use crate::template::{
+            history::{Run, RUNS_PER_DAY},
+            report::Step,
+            timings::Timings,
+        };
+        use std::collections::HashMap;
+        use tinyjson::JsonValue;
+
+        #[test]
+        fn prunes_history() {
+            let mut timings = get_mock_timings();
+            timings.data[0].nanos = vec![(Step::Part(1), 10.0)];
+            timings.data[1].nanos = vec![(Step::Part(1), 20.0)];
+
+            // the first day runs in every run, the second only in the first one.
+            for timestamp in 0..=RUNS_PER_DAY as u64 {
+                let data = if timestamp == 0 {
+                    timings.data.clone()
+                } else {
+                    vec![timings.data[0].clone()]
+                };
+                timings.record(Run {
+                    timestamp,
+                    commit: None,
+                    data,
+                });
+            }
+
+            assert_eq!(timings.history.len(), RUNS_PER_DAY + 1);
+            assert_eq!(timings.history[0].timestamp, 0);
+            assert_eq!(timings.history[0].data.len(), 1);
+            assert_eq!(timings.history[0].data[0].day, timings.data[1].day);
+            assert_eq!(timings.history[1].data[0].day, timings.data[0].day);
+
+            // days without a runtime, e.g. ones that are not solved yet, are not stored.
+            timings.record(Run {
+                timestamp: 100,
+                commit: None,
+                data: vec![timings.data[2].clone()],
+            });
+            assert_eq!(
+                timings.history.last().unwrap().timestamp,
+                RUNS_PER_DAY as u64
+            );
+        }
+
+        #[test]
+        fn round_trips_history() {
+            let mut timings = get_mock_timings();
+            timings.data[0].nanos = vec![(Step::Parse, 5.0), (Step::Part(1), 10.0)];
+            timings.record(Run {
+                timestamp: 42,
+                commit: None,
+                data: timings.data.clone(),
+            });
+
+            let json = JsonValue::from(timings).stringify().unwrap();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.history.len(), 1);
+            assert_eq!(timings.history[0].timestamp, 42);
+            assert_eq!(timings.history[0].commit, None);
+            assert_eq!(
+                timings.history[0].data[0].nanos,
+                vec![(Step::Parse, 5.0), (Step::Part(1), 10.0)]
+            );
+        }
+
+        #[test]
+        fn serializes_timings() {
+            let timings = get_mock_timings();
+            let value = JsonValue::try_from(timings).unwrap();
+            assert_eq!(
+                value
+                    .get::<HashMap<String, JsonValue>>()
+                    .unwrap()
+                    .get("data")
+                    .unwrap()
+                    .get::<Vec<JsonValue>>()
+                    .unwrap()
+                    .len(),
+                3
+            );
+        }
+    }
+
+    mod is_day_complete {
+        use crate::{
+            day,
+            template::timings::{Timing, Timings},
+        };
+
+        #[test]
+        fn handles_completed_days() {
+            let timings = Timings {
+                history: vec![],
+                data: vec![Timing {
+                    day: day!(1),
+                    parse: None,
+                    part_1: Some("1ms".into()),
+                    part_2: Some("2ms".into()),
+                    total_nanos: 3_000_000_000_f64,
+                    status: None,
+                    allocs: vec![],
+                    nanos: vec![],
+                }],
+            };
+
+            assert_eq!(timings.is_day_complete(day!(1)), true);
+        }
+
+        #[test]
+        fn handles_partial_days() {
+            let timings = Timings {
+                history: vec![],
+                data: vec![Timing {
+                    day: day!(1),
+                    parse: None,
+                    part_1: Some("1ms".into()),
+                    part_2: None,
+                    total_nanos: 1_000_000_000_f64,
+                    status: None,
+                    allocs: vec![],
+                    nanos: vec![],
+                }],
+            };
+
+            assert_eq!(timings.is_day_complete(day!(1)), false);
+        }
+
+        #[test]
+        fn handles_uncompleted_days() {
+            let timings = Timings {
+                history: vec![],
+                data: vec![Timing {
+                    day: day!(1),
+                    parse: None,
+                    part_1: None,
+                    part_2: None,
+                    total_nanos: 0.0,
+                    status: None,
+                    allocs: vec![],
+                    nanos: vec![],
+                }],
+            };
+
+            assert_eq!(timings.is_day_complete(day!(1)), false);
+        }
+    }
+
+    mod merge {
+        use crate::{
+            day,
+            template::timings::{Timing, Timings},
+        };
+
+        use super::get_mock_timings;
+
+        #[test]
+        fn handles_disjunct_timings() {
+            let timings = get_mock_timings();
+            let other = Timings {
+                history: vec![],
+                data: vec![Timing {
+                    day: day!(3),
+                    parse: None,
+                    part_1: None,
+                    part_2: None,
+                    total_nanos: 0_f64,
+                    status: None,
+                    allocs: vec![],
+                    nanos: vec![],
+                }],
+            };
+            let merged = timings.merge(&other);
+            assert_eq!(merged.data.len(), 4);
+            assert_eq!(merged.data[0].day, day!(1));
+            assert_eq!(merged.data[1].day, day!(2));
+            assert_eq!(merged.data[2].day, day!(3));
+            assert_eq!(merged.data[3].day, day!(4));
+        }
+
+        #[test]
+        fn handles_overlapping_timings() {
+            let timings = get_mock_timings();
+
+            let other = Timings {
+                history: vec![],
+                data: vec![Timing {
+                    day: day!(2),
+                    parse: None,
+                    part_1: None,
+                    part_2: None,
+                    total_nanos: 0_f64,
+                    status: None,
+                    allocs: vec![],
+                    nanos: vec![],
+                }],
+            };
+            let merged = timings.merge(&other);
+
+            assert_eq!(merged.data.len(), 3);
+            assert_eq!(merged.data[0].day, day!(1));
+            assert_eq!(merged.data[1].day, day!(2));
+            assert_eq!(merged.data[1].total_nanos, 0_f64);
+            assert_eq!(merged.data[2].day, day!(4));
+        }
+
+        #[test]
+        fn handles_empty_timings() {
+            let timings = Timings::default();
+            let other = get_mock_timings();
+            let merged = timings.merge(&other);
+            assert_eq!(merged.data.len(), 3);
+        }
+
+        #[test]
+        fn handles_empty_other_timings() {
+            let timings = get_mock_timings();
+            let other = Timings::default();
+            let merged = timings.merge(&other);
+            assert_eq!(merged.data.len(), 3);
+        }
+    }
+}